@@ -0,0 +1,12 @@
+#![no_main]
+
+use aoc_2025::day_10::{day_10_part_1, day_10_part_2};
+use libfuzzer_sys::fuzz_target;
+
+// Any input is fine, as long as nothing panics.
+fuzz_target!(|data: &[u8]| {
+    if let Ok(data) = std::str::from_utf8(data) {
+        let _ = day_10_part_1(data);
+        let _ = day_10_part_2(data);
+    }
+});
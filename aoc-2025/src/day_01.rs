@@ -0,0 +1,856 @@
+use std::io::BufRead;
+
+use nom::{
+    IResult, Parser,
+    branch::alt,
+    bytes::complete::tag,
+    combinator::{map_opt, value},
+};
+
+use aoc_core::error::InputError;
+use aoc_core::generate::{Rng, render};
+use aoc_core::parsers::{lines, parse_input};
+
+fn parse_rotation(input: &str) -> IResult<&str, i64> {
+    map_opt(
+        (
+            alt((value(-1, tag("L")), value(1, tag("R")))),
+            nom::character::complete::i64,
+        ),
+        |(direction, distance)| distance.checked_mul(direction),
+    )
+    .parse(input)
+}
+
+fn too_many_passes() -> InputError {
+    InputError::invalid("Too many passes to fit in an i64")
+}
+
+fn parse_input_data(data: &str) -> Result<Vec<i64>, InputError> {
+    parse_input(lines(parse_rotation), data)
+}
+
+// The safe's dial, generalised: any number of positions, any start,
+// and any positions to count instead of just 0.
+//
+// A rotation is a number of clicks, negative to the left.
+// The passes of a rotation are the clicks landing on a target, the last one included,
+// so part 1 counts the rotations stopping on 0 and part 2 sums the passes.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Dial {
+    size: i64,
+    position: i64,
+    targets: Vec<i64>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Turn {
+    pub position: i64,
+    pub passes: i64,
+}
+
+// The rotations to stop on every position of a list, with as few clicks as possible.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Plan {
+    pub rotations: Vec<i64>,
+    pub clicks: i64,
+    pub passes: i64,
+}
+
+impl Dial {
+    pub fn new(size: i64, start: i64, targets: &[i64]) -> Result<Self, InputError> {
+        if size <= 0 {
+            return Err(InputError::invalid("A dial needs at least one position"));
+        }
+        let on_dial = |position: &i64| (0..size).contains(position);
+        if !on_dial(&start) || !targets.iter().all(on_dial) {
+            return Err(InputError::invalid(format!(
+                "The positions must be between 0 and {}",
+                size - 1
+            )));
+        }
+        let mut targets = targets.to_vec();
+        targets.sort_unstable();
+        targets.dedup();
+        Ok(Dial {
+            size,
+            position: start,
+            targets,
+        })
+    }
+
+    // 100 positions, starting at 50, counting the zeros.
+    pub fn safe() -> Self {
+        Dial::new(100, 50, &[0]).expect("Valid dial")
+    }
+
+    pub fn position(&self) -> i64 {
+        self.position
+    }
+
+    // Any rotation works, i64::MIN included. The passes are at most the number of
+    // clicks, so they only saturate at i64::MAX for a rotation of i64::MIN on a dial
+    // where every position is a target.
+    pub fn apply(&mut self, rotation: i64) -> Turn {
+        let distance = rotation.unsigned_abs();
+        let size = self.size as u64;
+        let passes = self
+            .targets
+            .iter()
+            .map(|&target| {
+                // the clicks until the first time on the target
+                let first = if rotation < 0 {
+                    (self.position - target).rem_euclid(self.size)
+                } else {
+                    (target - self.position).rem_euclid(self.size)
+                } as u64;
+                let first = if first == 0 { size } else { first };
+                if distance < first {
+                    0
+                } else {
+                    (distance - first) / size + 1
+                }
+            })
+            .sum::<u64>();
+        // both below size, so their sum fits in a u64
+        let offset = rotation.rem_euclid(self.size) as u64;
+        self.position = ((self.position as u64 + offset) % size) as i64;
+        Turn {
+            position: self.position,
+            passes: i64::try_from(passes).unwrap_or(i64::MAX),
+        }
+    }
+
+    pub fn is_on_target(&self) -> bool {
+        self.targets.binary_search(&self.position).is_ok()
+    }
+
+    // Every leg is independent, it starts where the previous one stopped, so the
+    // shortest direction is the best for each. When both are as short, the one
+    // passing fewer targets is taken.
+    pub fn plan(&self, stops: &[i64]) -> Result<Plan, InputError> {
+        if let Some(stop) = stops.iter().find(|stop| !(0..self.size).contains(*stop)) {
+            return Err(InputError::invalid(format!("{} is not on the dial", stop)));
+        }
+        let mut dial = self.clone();
+        let mut plan = Plan {
+            rotations: Vec::with_capacity(stops.len()),
+            clicks: 0,
+            passes: 0,
+        };
+        for &stop in stops {
+            let right = (stop - dial.position).rem_euclid(self.size);
+            let left = (dial.position - stop).rem_euclid(self.size);
+            let (rotation, turn) = [right, -left]
+                .into_iter()
+                .map(|rotation| (rotation, dial.clone().apply(rotation)))
+                .min_by_key(|(rotation, turn)| (rotation.abs(), turn.passes))
+                .expect("Two directions");
+            dial.apply(rotation);
+            plan.rotations.push(rotation);
+            plan.clicks += rotation.abs();
+            plan.passes += turn.passes;
+        }
+        Ok(plan)
+    }
+}
+
+pub fn day_01_part_1_with(data: &str, mut dial: Dial) -> Result<i64, InputError> {
+    let data = parse_input_data(data)?;
+    Ok(data
+        .iter()
+        .filter(|&&rotation| {
+            dial.apply(rotation);
+            dial.is_on_target()
+        })
+        .count() as i64)
+}
+
+pub fn day_01_part_2_with(data: &str, mut dial: Dial) -> Result<i64, InputError> {
+    let data = parse_input_data(data)?;
+    data.iter()
+        .try_fold(0_i64, |sum, &rotation| {
+            sum.checked_add(dial.apply(rotation).passes)
+        })
+        .ok_or_else(too_many_passes)
+}
+
+// What a rotation did, to compare a line with the expected counts.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Event {
+    pub line: usize,
+    pub rotation: i64,
+    pub start: i64,
+    pub end: i64,
+    // the zero crossings on the safe's dial
+    pub passes: i64,
+    pub on_target: bool,
+}
+
+pub fn day_01_events_with(
+    data: &str,
+    mut dial: Dial,
+) -> Result<impl Iterator<Item = Event>, InputError> {
+    let data = parse_input_data(data)?;
+    Ok(data.into_iter().enumerate().map(move |(index, rotation)| {
+        let start = dial.position();
+        let turn = dial.apply(rotation);
+        Event {
+            line: index + 1,
+            rotation,
+            start,
+            end: turn.position,
+            passes: turn.passes,
+            on_target: dial.is_on_target(),
+        }
+    }))
+}
+
+pub fn day_01_events(data: &str) -> Result<impl Iterator<Item = Event>, InputError> {
+    day_01_events_with(data, Dial::safe())
+}
+
+// The events as a table, with the running counts of both parts.
+pub fn day_01_explain(data: &str) -> Result<String, InputError> {
+    let mut table = vec![format!(
+        "{:>6} {:>10} {:>5} {:>5} {:>6} {:>8} {:>6} {:>6}",
+        "line", "rotation", "start", "end", "zeros", "on zero", "part 1", "part 2"
+    )];
+    let (mut part_1, mut part_2) = (0, 0);
+    for event in day_01_events(data)? {
+        part_1 += event.on_target as i64;
+        part_2 = event
+            .passes
+            .checked_add(part_2)
+            .ok_or_else(too_many_passes)?;
+        let direction = if event.rotation < 0 { 'L' } else { 'R' };
+        table.push(format!(
+            "{:>6} {:>10} {:>5} {:>5} {:>6} {:>8} {:>6} {:>6}",
+            event.line,
+            format!("{}{}", direction, event.rotation.unsigned_abs()),
+            event.start,
+            event.end,
+            event.passes,
+            if event.on_target { "yes" } else { "" },
+            part_1,
+            part_2
+        ));
+    }
+    Ok(table.join("\n"))
+}
+
+// Counting while reading the rotations one line at a time, for the inputs too big
+// to be kept in memory. The counters are the answers of both parts so far.
+#[derive(Debug, Clone)]
+pub struct DialCounter {
+    dial: Dial,
+    nb_lines: usize,
+    nb_rotations: usize,
+    // after a blank line or trailing whitespace, only whitespace can follow
+    ended: bool,
+    part_1: i64,
+    part_2: i64,
+}
+
+impl DialCounter {
+    pub fn new(dial: Dial) -> Self {
+        DialCounter {
+            dial,
+            nb_lines: 0,
+            nb_rotations: 0,
+            ended: false,
+            part_1: 0,
+            part_2: 0,
+        }
+    }
+
+    pub fn push(&mut self, rotation: i64) -> Result<Turn, InputError> {
+        let turn = self.dial.apply(rotation);
+        self.part_1 += self.dial.is_on_target() as i64;
+        self.part_2 = self
+            .part_2
+            .checked_add(turn.passes)
+            .ok_or_else(too_many_passes)?;
+        Ok(turn)
+    }
+
+    // The errors are the ones of the whole input, with the same line numbers.
+    pub fn push_line(&mut self, line: &str) -> Result<(), InputError> {
+        self.nb_lines += 1;
+        // the line ending is \n or \r\n, like in the whole input
+        let line = line.strip_suffix('\r').unwrap_or(line);
+        // blank lines are only fine at the end
+        if line.trim().is_empty() {
+            self.ended = true;
+            return Ok(());
+        }
+        // the position in the line, as if the whole input was parsed
+        let error = |rest: &str| match InputError::parse(line, rest) {
+            InputError::Parse { column, near, .. } => InputError::Parse {
+                line: self.nb_lines,
+                column,
+                near,
+            },
+            error => error,
+        };
+        if self.ended {
+            return Err(error(line));
+        }
+        let rotation = match parse_rotation(line) {
+            Ok(("", rotation)) => rotation,
+            // trailing whitespace is only fine on the last rotation
+            Ok((rest, rotation)) if rest.trim().is_empty() => {
+                self.ended = true;
+                rotation
+            }
+            Ok((rest, _)) => return Err(error(rest)),
+            // a rotation is parsed entirely or not at all
+            Err(_) => return Err(error(line)),
+        };
+        self.nb_rotations += 1;
+        self.push(rotation)?;
+        Ok(())
+    }
+
+    pub fn part_1(&self) -> i64 {
+        self.part_1
+    }
+
+    pub fn part_2(&self) -> i64 {
+        self.part_2
+    }
+
+    fn has_rotations(&self) -> bool {
+        self.nb_rotations > 0
+    }
+}
+
+pub fn day_01_read_with<R: BufRead>(reader: R, dial: Dial) -> Result<DialCounter, InputError> {
+    let mut counter = DialCounter::new(dial);
+    for line in reader.lines() {
+        let line = line.map_err(|error| {
+            InputError::invalid(format!(
+                "Failed to read line {}: {}",
+                counter.nb_lines + 1,
+                error
+            ))
+        })?;
+        counter.push_line(&line)?;
+    }
+    if !counter.has_rotations() {
+        // at the end, after the last newline
+        return Err(InputError::Parse {
+            line: counter.nb_lines + 1,
+            column: 1,
+            near: String::new(),
+        });
+    }
+    Ok(counter)
+}
+
+// Both parts at once, from a file for example.
+pub fn day_01_read<R: BufRead>(reader: R) -> Result<(i64, i64), InputError> {
+    let counter = day_01_read_with(reader, Dial::safe())?;
+    Ok((counter.part_1(), counter.part_2()))
+}
+
+pub fn day_01_part_1(data: &str) -> Result<i64, InputError> {
+    day_01_part_1_with(data, Dial::safe())
+}
+
+pub fn day_01_part_2(data: &str) -> Result<i64, InputError> {
+    day_01_part_2_with(data, Dial::safe())
+}
+
+// Rotations of up to 1000 clicks.
+pub fn day_01_generate(rng: &mut Rng, size: usize) -> String {
+    render(
+        (0..size.max(1)).map(|_| {
+            let direction = if rng.chance(0.5) { 'L' } else { 'R' };
+            format!("{}{}", direction, rng.between(1, 1000))
+        }),
+        "\n",
+    )
+}
+
+// Quite an extensive set of tests as I tried to implement part 2
+// while being sick. I tried a lot of edge cases suggested by redditors,
+// without luck.
+//
+// It was much easier the next day.
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_core::generate::Rng;
+    use proptest::prelude::*;
+
+    const EXAMPLE: &str = "L68
+L30
+R48
+L5
+R60
+L55
+L1
+L99
+R14
+L82";
+
+    #[test]
+    fn test_day_01_part_1() {
+        assert_eq!(day_01_part_1(EXAMPLE), Ok(3));
+    }
+
+    #[test]
+    fn test_day_01_part_2() {
+        assert_eq!(day_01_part_2(EXAMPLE), Ok(6));
+    }
+
+    #[test]
+    fn test_day_01_part_2_minimal() {
+        assert_eq!(day_01_part_2("L150"), Ok(2));
+        assert_eq!(day_01_part_2("R150"), Ok(2));
+    }
+
+    #[test]
+    fn test_day_01_part_2_minimal2() {
+        assert_eq!(day_01_part_2("L51"), Ok(1));
+        assert_eq!(day_01_part_2("L1"), Ok(0));
+        assert_eq!(day_01_part_2("L50"), Ok(1));
+        assert_eq!(day_01_part_2("R50"), Ok(1));
+        assert_eq!(day_01_part_2("L50\nL100"), Ok(2));
+        assert_eq!(day_01_part_2("L50\nR100"), Ok(2));
+    }
+
+    #[test]
+    fn test_day_01_part_2_minimal3() {
+        assert_eq!(day_01_part_2("R75"), Ok(1));
+    }
+
+    #[test]
+    fn test_day_01_part_2_minimal4() {
+        assert_eq!(day_01_part_2("R49"), Ok(0));
+    }
+
+    #[test]
+    fn test_day_01_part_2_many() {
+        assert_eq!(day_01_part_2("R1000"), Ok(10));
+        assert_eq!(day_01_part_2("L50\nR1000"), Ok(11));
+    }
+
+    #[test]
+    fn test_day_01_part_2_many2() {
+        assert_eq!(day_01_part_2("L1000"), Ok(10));
+        assert_eq!(day_01_part_2("R50\nL1000"), Ok(11));
+        assert_eq!(day_01_part_2("L150"), Ok(2));
+        assert_eq!(day_01_part_2("L250"), Ok(3));
+        assert_eq!(day_01_part_2("L650"), Ok(7));
+    }
+
+    #[test]
+    fn test_1() {
+        // 50 -> 99 -> 01 without crossing zero
+        let count = day_01_part_2("R49\nL98");
+        assert_eq!(count, Ok(0));
+    }
+
+    #[test]
+    fn test_2() {
+        // 50 -> 99 -> 00 ending up at zero
+        let count = day_01_part_2("R49\nR1");
+        assert_eq!(count, Ok(1));
+    }
+
+    #[test]
+    fn test_3() {
+        // 50 -> 99 -> 00 -> 01 stopping at zero once
+        let count = day_01_part_2("R49\nR1\nR1");
+        assert_eq!(count, Ok(1));
+    }
+
+    #[test]
+    fn test_4() {
+        // 50 -> 01 -> 00 -> 99 stopping at zero once
+        let count = day_01_part_2("R49\nR1\nL1");
+        assert_eq!(count, Ok(1));
+    }
+
+    #[test]
+    fn test_5() {
+        // 50 -> 00 -> and a full rotation ending up at 00 again
+        let count = day_01_part_2("L50\nL100");
+        assert_eq!(count, Ok(2));
+    }
+
+    #[test]
+    fn test_6() {
+        // 50 -> 00 -> and a full rotation ending up at 00 again
+        let count = day_01_part_2("R50\nR100");
+        assert_eq!(count, Ok(2));
+    }
+
+    #[test]
+    fn test_7() {
+        // 50 -> 00 -> and 4 full rotations ending up at 00 again
+        let count = day_01_part_2("L50\nL400");
+        assert_eq!(count, Ok(5));
+    }
+
+    #[test]
+    fn test_8() {
+        // 50 -> 00 -> and 4 full rotations ending up at 00 again
+        let count = day_01_part_2("L50\nR400");
+        assert_eq!(count, Ok(5));
+    }
+
+    #[test]
+    fn test_9() {
+        // 50 and 10 full rotations ending up at 50 again
+        let count = day_01_part_2("R1000");
+        assert_eq!(count, Ok(10));
+    }
+
+    #[test]
+    fn test_again() {
+        assert_eq!(day_01_part_2("L50\nR101"), Ok(2));
+        assert_eq!(day_01_part_2("L50\nL1"), Ok(1));
+    }
+
+    #[test]
+    fn test_again2() {
+        assert_eq!(day_01_part_2("L50\nR400"), Ok(5));
+    }
+
+    #[test]
+    fn test_again3() {
+        assert_eq!(day_01_part_2("L50\nR50"), Ok(1));
+        assert_eq!(day_01_part_2("L50\nL50"), Ok(1));
+        assert_eq!(day_01_part_2("R50\nR50\nL50\nL50\nR75\nL50"), Ok(4));
+        assert_eq!(day_01_part_2("L75\nR50"), Ok(2));
+    }
+
+    #[test]
+    fn test_again4() {
+        assert_eq!(
+            day_01_part_2("R50\nR50\nL50\nL50\nR75\nL50\nL25\nL75\nR50"),
+            Ok(6)
+        );
+    }
+
+    #[test]
+    fn test_actual_fail() {
+        assert_eq!(day_01_part_2("R5\nR925"), Ok(9));
+    }
+
+    #[test]
+    fn test_actual_fail2() {
+        assert_eq!(day_01_part_2("R30\nL687"), Ok(7));
+    }
+
+    #[test]
+    fn test_day_01_generate() {
+        let data = day_01_generate(&mut Rng::new(1), 100);
+        assert_eq!(data.lines().count(), 100);
+        let (part_1, part_2) = naive_day_01(&data);
+        assert_eq!(day_01_part_1(&data), Ok(part_1));
+        assert_eq!(day_01_part_2(&data), Ok(part_2));
+    }
+
+    #[test]
+    fn test_day_01_invalid_input() {
+        assert!(day_01_part_1("L68\nU30").is_err());
+        assert!(day_01_part_2("R99999999999999999999").is_err());
+        assert!(day_01_part_1("L-9223372036854775808").is_err());
+        assert_eq!(day_01_part_1("R5000000000\nL5000000050"), Ok(1));
+        assert_eq!(day_01_part_2("R5000000000\nL5000000050"), Ok(100_000_001));
+        assert!(day_01_part_2("R9223372036854775807\nR9223372036854775807").is_ok());
+        let data = vec!["R9223372036854775807"; 101].join("\n");
+        assert!(day_01_part_2(&data).is_err());
+        assert!(day_01_read(data.as_bytes()).is_err());
+        assert!(day_01_explain(&data).is_err());
+    }
+
+    // Reference implementation, turning the dial one click at a time.
+    fn naive_dial(size: i64, start: i64, targets: &[i64], data: &str) -> (i64, i64) {
+        let mut position = start;
+        let (mut stops_on_target, mut clicks_on_target) = (0, 0);
+        for line in data.lines() {
+            let (direction, distance) = line.split_at(1);
+            let step = if direction == "L" { size - 1 } else { 1 };
+            for _ in 0..distance.parse::<i64>().unwrap() {
+                position = (position + step) % size;
+                if targets.contains(&position) {
+                    clicks_on_target += 1;
+                }
+            }
+            if targets.contains(&position) {
+                stops_on_target += 1;
+            }
+        }
+        (stops_on_target, clicks_on_target)
+    }
+
+    fn naive_day_01(data: &str) -> (i64, i64) {
+        naive_dial(100, 50, &[0], data)
+    }
+
+    fn rotations() -> impl Strategy<Value = String> {
+        prop::collection::vec((prop::bool::ANY, 0..350_i64), 1..20).prop_map(|rotations| {
+            render(
+                rotations.iter().map(|(left, distance)| {
+                    format!("{}{}", if *left { 'L' } else { 'R' }, distance)
+                }),
+                "\n",
+            )
+        })
+    }
+
+    fn dials() -> impl Strategy<Value = (i64, i64, Vec<i64>)> {
+        (1..30_i64)
+            .prop_flat_map(|size| (Just(size), 0..size, prop::collection::vec(0..size, 1..4)))
+    }
+
+    proptest! {
+        #[test]
+        fn test_day_01_against_naive(data in rotations()) {
+            let (part_1, part_2) = naive_day_01(&data);
+            prop_assert_eq!(day_01_part_1(&data), Ok(part_1));
+            prop_assert_eq!(day_01_part_2(&data), Ok(part_2));
+        }
+
+        #[test]
+        fn test_day_01_read_against_batch(data in rotations()) {
+            let parts = day_01_read(data.as_bytes());
+            prop_assert_eq!(parts.clone().map(|(part_1, _)| part_1), day_01_part_1(&data));
+            prop_assert_eq!(parts.map(|(_, part_2)| part_2), day_01_part_2(&data));
+        }
+
+        #[test]
+        fn test_dial_against_naive((size, start, targets) in dials(), data in rotations()) {
+            let (part_1, part_2) = naive_dial(size, start, &targets, &data);
+            let dial = Dial::new(size, start, &targets).unwrap();
+            prop_assert_eq!(day_01_part_1_with(&data, dial.clone()), Ok(part_1));
+            prop_assert_eq!(day_01_part_2_with(&data, dial), Ok(part_2));
+        }
+    }
+
+    #[test]
+    fn test_dial() {
+        let mut dial = Dial::new(10, 5, &[0, 3, 3]).unwrap();
+        assert_eq!(
+            dial.apply(-2),
+            Turn {
+                position: 3,
+                passes: 1
+            }
+        );
+        assert!(dial.is_on_target());
+        assert_eq!(
+            dial.apply(27),
+            Turn {
+                position: 0,
+                passes: 5
+            }
+        );
+        assert_eq!(
+            dial.apply(0),
+            Turn {
+                position: 0,
+                passes: 0
+            }
+        );
+        assert_eq!(
+            dial.apply(-10),
+            Turn {
+                position: 0,
+                passes: 2
+            }
+        );
+        assert_eq!(dial.position(), 0);
+        assert_eq!(
+            day_01_part_2_with("R49\nL99", Dial::new(100, 50, &[0, 99]).unwrap()),
+            Ok(2)
+        );
+    }
+
+    #[test]
+    fn test_day_01_events() {
+        let events: Vec<Event> = day_01_events(EXAMPLE).unwrap().collect();
+        assert_eq!(events.len(), 10);
+        assert_eq!(
+            events[0],
+            Event {
+                line: 1,
+                rotation: -68,
+                start: 50,
+                end: 82,
+                passes: 1,
+                on_target: false
+            }
+        );
+        assert_eq!(events[2].end, 0);
+        assert!(events[2].on_target);
+        let nb_stops = events.iter().filter(|event| event.on_target).count() as i64;
+        let nb_passes = events.iter().map(|event| event.passes).sum();
+        assert_eq!(day_01_part_1(EXAMPLE), Ok(nb_stops));
+        assert_eq!(day_01_part_2(EXAMPLE), Ok(nb_passes));
+    }
+
+    #[test]
+    fn test_day_01_explain() {
+        let table = day_01_explain("L50\nR101").unwrap();
+        let lines: Vec<&str> = table.lines().collect();
+        assert_eq!(lines.len(), 3);
+        assert!(lines[0].trim_start().starts_with("line"));
+        let row: Vec<&str> = lines[2].split_whitespace().collect();
+        assert_eq!(row, ["2", "R101", "0", "1", "1", "1", "2"]);
+        assert!(day_01_explain("L50\nX1").is_err());
+    }
+
+    #[test]
+    fn test_day_01_read() {
+        assert_eq!(day_01_read(EXAMPLE.as_bytes()), Ok((3, 6)));
+        assert_eq!(day_01_read("L50\r\nR101\n\n".as_bytes()), Ok((1, 2)));
+
+        let mut counter = DialCounter::new(Dial::safe());
+        for line in EXAMPLE.lines().take(3) {
+            counter.push_line(line).unwrap();
+        }
+        assert_eq!((counter.part_1(), counter.part_2()), (1, 2));
+    }
+
+    #[test]
+    fn test_day_01_read_errors() {
+        // the same errors as the whole input
+        for data in [
+            "",
+            "\n",
+            "L1\n\nR2",
+            "L1\nR2\nU3",
+            "L1\nR",
+            "L1\n R2",
+            "R2x",
+            "L68  ",
+            "L68  \nR1",
+            "L68\t\r\nR1",
+            "L1\n  \nR2",
+            "L1\n  \n",
+            "L1\r\nR2\r\n",
+            " \n",
+        ] {
+            assert_eq!(
+                day_01_read(data.as_bytes()).map(|(part_1, _)| part_1),
+                day_01_part_1(data),
+                "{:?}",
+                data
+            );
+        }
+        assert!(day_01_read(&[b'L', 0xff][..]).is_err());
+    }
+
+    fn as_input(rotations: &[i64]) -> String {
+        render(
+            rotations.iter().map(|rotation| {
+                format!(
+                    "{}{}",
+                    if *rotation < 0 { 'L' } else { 'R' },
+                    rotation.abs()
+                )
+            }),
+            "\n",
+        )
+    }
+
+    // Every combination of rotations of up to two turns in both directions,
+    // the fewest clicks and then the fewest passes.
+    fn brute_force_plan(dial: &Dial, stops: &[i64]) -> (i64, i64) {
+        let size = dial.size;
+        let mut best: Option<(i64, i64)> = None;
+        let mut stack = vec![(dial.clone(), 0, 0, 0)];
+        while let Some((dial, index, clicks, passes)) = stack.pop() {
+            if index == stops.len() {
+                best = Some(best.map_or((clicks, passes), |best| best.min((clicks, passes))));
+                continue;
+            }
+            for rotation in -2 * size..=2 * size {
+                let mut next = dial.clone();
+                let turn = next.apply(rotation);
+                if turn.position == stops[index] {
+                    stack.push((
+                        next,
+                        index + 1,
+                        clicks + rotation.abs(),
+                        passes + turn.passes,
+                    ));
+                }
+            }
+        }
+        best.expect("At least one plan")
+    }
+
+    #[test]
+    fn test_plan() {
+        let plan = Dial::safe().plan(&[20, 90, 90, 10]).unwrap();
+        assert_eq!(plan.rotations, [-30, -30, 0, 20]);
+        assert_eq!(plan.clicks, 80);
+        assert_eq!(plan.passes, 2);
+        assert_eq!(day_01_part_2(&as_input(&plan.rotations)), Ok(2));
+
+        // 50 clicks both ways, only the left passes 0
+        let plan = Dial::new(100, 50, &[0]).unwrap().plan(&[0, 50]).unwrap();
+        assert_eq!((plan.clicks, plan.passes), (100, 1));
+        assert!(Dial::safe().plan(&[100]).is_err());
+        assert_eq!(Dial::safe().plan(&[]).unwrap().clicks, 0);
+    }
+
+    proptest! {
+        #[test]
+        fn test_plan_against_brute_force(
+            (size, start, targets) in dials(),
+            stops in prop::collection::vec(0..30_i64, 0..4),
+        ) {
+            let dial = Dial::new(size, start, &targets).unwrap();
+            let stops: Vec<i64> = stops.iter().map(|stop| stop % size).collect();
+            let plan = dial.plan(&stops).unwrap();
+            prop_assert_eq!((plan.clicks, plan.passes), brute_force_plan(&dial, &stops));
+            let mut check = dial.clone();
+            for (rotation, stop) in plan.rotations.iter().zip(&stops) {
+                prop_assert_eq!(check.apply(*rotation).position, *stop);
+            }
+        }
+    }
+
+    #[test]
+    fn test_dial_extreme_rotations() {
+        let mut dial = Dial::safe();
+        assert_eq!(
+            dial.apply(i64::MIN),
+            Turn {
+                position: 42,
+                passes: 92_233_720_368_547_758
+            }
+        );
+        assert_eq!(dial.apply(i64::MAX).position, 49);
+
+        let mut dial = Dial::new(i64::MAX, i64::MAX - 1, &[0]).unwrap();
+        assert_eq!(
+            dial.apply(i64::MAX - 1),
+            Turn {
+                position: i64::MAX - 2,
+                passes: 1
+            }
+        );
+        assert_eq!(dial.apply(i64::MIN).position, i64::MAX - 3);
+
+        let mut dial = Dial::new(1, 0, &[0]).unwrap();
+        assert_eq!(dial.apply(i64::MIN).passes, i64::MAX);
+        assert_eq!(dial.apply(i64::MAX).passes, i64::MAX);
+    }
+
+    #[test]
+    fn test_invalid_dial() {
+        assert!(Dial::new(0, 0, &[0]).is_err());
+        assert!(Dial::new(10, 10, &[0]).is_err());
+        assert!(Dial::new(10, 5, &[-1]).is_err());
+        assert!(Dial::new(1, 0, &[]).is_ok());
+    }
+}
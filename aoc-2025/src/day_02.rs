@@ -0,0 +1,741 @@
+/*
+    The invalid IDs are a pattern repeated, so they are pattern * multiplier,
+    with a multiplier like 1001 for a pattern of 3 digits repeated twice.
+    Between two bounds, the patterns are consecutive numbers, and their sum
+    is the one of an arithmetic series. Nothing depends on the width of the ranges.
+
+    For part 2, 111111 is 1 repeated 6 times, 11 repeated 3 times and 111 twice,
+    so summing every period counts it three times. Möbius sorts this out
+    over the divisors of the number of digits, no set of IDs needed.
+
+    None of this is specific to base 10, the puzzle is only its decimal case.
+    RepeatedIds works in any radix from 2 to 36, with IDs up to u128::MAX.
+    It can also go through the invalid IDs one by one, in order, when the sum
+    isn't enough, by merging the progressions of the different pattern lengths.
+*/
+
+use std::collections::VecDeque;
+
+use nom::{IResult, Parser, character::complete::alphanumeric1, combinator::map_res};
+
+use aoc_core::error::InputError;
+use aoc_core::generate::{Rng, render};
+use aoc_core::parsers::{comma_separated, parse_input, range};
+
+// Which repeated patterns are invalid IDs: part 1 or part 2.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Mode {
+    Twice,
+    AtLeastTwice,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RepeatedIds {
+    radix: u32,
+}
+
+// The repeated numbers that don't fit in a u128 are after any range.
+fn repeat(pattern: u128, divisor: u128, nb_repeats: u32) -> Option<u128> {
+    (0..nb_repeats).try_fold(0_u128, |number, _| {
+        number.checked_mul(divisor)?.checked_add(pattern)
+    })
+}
+
+fn mobius(mut n: u32) -> i32 {
+    let mut mobius = 1;
+    let mut factor = 2;
+    while factor * factor <= n {
+        if n.is_multiple_of(factor) {
+            n /= factor;
+            if n.is_multiple_of(factor) {
+                return 0;
+            }
+            mobius = -mobius;
+        }
+        factor += 1;
+    }
+    if n > 1 { -mobius } else { mobius }
+}
+
+// The numbers pattern * multiplier, for the patterns from first to last,
+// all with the same number of digits.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Patterns {
+    multiplier: u128,
+    nb_repeats: u32,
+    first: u128,
+    last: u128,
+}
+
+impl Patterns {
+    fn count(&self) -> u128 {
+        self.last - self.first + 1
+    }
+
+    // An arithmetic series, None when it doesn't fit in a u128.
+    fn sum(&self) -> Option<u128> {
+        let count = self.count();
+        // one of the two factors is even
+        let bounds = self.first.checked_add(self.last)?;
+        let (count, bounds) = if count.is_multiple_of(2) {
+            (count / 2, bounds)
+        } else {
+            (count, bounds / 2)
+        };
+        self.multiplier.checked_mul(count)?.checked_mul(bounds)
+    }
+
+    // The first and last numbers, none of them can overflow
+    // since they are in the range.
+    fn front(&self) -> u128 {
+        self.first * self.multiplier
+    }
+
+    fn back(&self) -> u128 {
+        self.last * self.multiplier
+    }
+}
+
+// The lengths of the patterns making invalid IDs of nb_digits digits, smallest first.
+fn pattern_lens(nb_digits: u32, mode: Mode) -> impl Iterator<Item = u32> {
+    (1..nb_digits).filter(move |&pattern_len| match mode {
+        Mode::Twice => pattern_len * 2 == nb_digits,
+        Mode::AtLeastTwice => nb_digits.is_multiple_of(pattern_len),
+    })
+}
+
+// An invalid ID, with the pattern, under the selected mode, that the ID repeats.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct InvalidId {
+    pub id: u128,
+    pub pattern: u128,
+    pub nb_repeats: u32,
+}
+
+impl InvalidId {
+    fn new(patterns: &Patterns, pattern: u128) -> Self {
+        InvalidId {
+            id: pattern * patterns.multiplier,
+            pattern,
+            nb_repeats: patterns.nb_repeats,
+        }
+    }
+}
+
+// The invalid IDs of a range in increasing order, from both ends.
+// For each number of digits, the progressions of every pattern length are merged,
+// and the numbers they have in common are only given once.
+#[derive(Debug, Clone)]
+pub struct InvalidIds {
+    by_nb_digits: VecDeque<Vec<Patterns>>,
+}
+
+// Move past the number id at one end of every progression having it there.
+fn skip(group: &mut Vec<Patterns>, id: u128, end: fn(&Patterns) -> u128, next: fn(&mut Patterns)) {
+    group
+        .iter_mut()
+        .filter(|patterns| end(patterns) == id)
+        .for_each(next);
+    group.retain(|patterns| patterns.first <= patterns.last);
+}
+
+impl Iterator for InvalidIds {
+    type Item = InvalidId;
+
+    fn next(&mut self) -> Option<InvalidId> {
+        loop {
+            let group = self.by_nb_digits.front_mut()?;
+            // on a tie, the shortest pattern comes first
+            let Some(smallest) = group.iter().min_by_key(|patterns| patterns.front()) else {
+                self.by_nb_digits.pop_front();
+                continue;
+            };
+            let invalid_id = InvalidId::new(smallest, smallest.first);
+            skip(group, invalid_id.id, Patterns::front, |patterns| {
+                patterns.first += 1
+            });
+            return Some(invalid_id);
+        }
+    }
+}
+
+impl DoubleEndedIterator for InvalidIds {
+    fn next_back(&mut self) -> Option<InvalidId> {
+        loop {
+            let group = self.by_nb_digits.back_mut()?;
+            // max_by_key keeps the last of the ties, the shortest pattern once reversed
+            let Some(largest) = group.iter().rev().max_by_key(|patterns| patterns.back()) else {
+                self.by_nb_digits.pop_back();
+                continue;
+            };
+            let invalid_id = InvalidId::new(largest, largest.last);
+            skip(group, invalid_id.id, Patterns::back, |patterns| {
+                patterns.last -= 1
+            });
+            return Some(invalid_id);
+        }
+    }
+}
+
+impl RepeatedIds {
+    pub const DECIMAL: RepeatedIds = RepeatedIds { radix: 10 };
+
+    pub fn new(radix: u32) -> Result<Self, InputError> {
+        if !(2..=36).contains(&radix) {
+            return Err(InputError::invalid(format!("Invalid radix: {}", radix)));
+        }
+        Ok(RepeatedIds { radix })
+    }
+
+    pub fn radix(self) -> u32 {
+        self.radix
+    }
+
+    // 0 is a single digit, like the others below the radix.
+    fn nb_digits(self, number: u128) -> u32 {
+        number.checked_ilog(self.radix as u128).unwrap_or(0) + 1
+    }
+
+    // The numbers of nb_digits digits between start and end that are
+    // a pattern of pattern_len digits repeated, None if there are none.
+    // The patterns are at most half the digits of a u128, their powers fit.
+    fn patterns(
+        self,
+        (start, end): (u128, u128),
+        nb_digits: u32,
+        pattern_len: u32,
+    ) -> Option<Patterns> {
+        let divisor = (self.radix as u128).pow(pattern_len);
+        let nb_repeats = nb_digits / pattern_len;
+        let multiplier = repeat(1, divisor, nb_repeats)?;
+        // the patterns can't start with a 0
+        let first = (divisor / self.radix as u128).max(start.div_ceil(multiplier));
+        let last = (divisor - 1).min(end / multiplier);
+        (first <= last).then_some(Patterns {
+            multiplier,
+            nb_repeats,
+            first,
+            last,
+        })
+    }
+
+    // A total over the invalid IDs, from the totals of the progressions.
+    // With Mode::Twice there is a single pattern length per number of digits,
+    // nothing is counted twice.
+    //
+    // For Mode::AtLeastTwice, the numbers with a period also have its multiples
+    // as periods. By Möbius inversion, the numbers of d digits whose smallest period
+    // is d are Σ μ(d/k)·patterns(k) over the divisors k of d, and the repeated ones
+    // are all the others: the opposite of the same sum without k = d.
+    // The terms are added and removed apart, so nothing is ever negative.
+    fn total(
+        self,
+        range: (u128, u128),
+        mode: Mode,
+        total: fn(&Patterns) -> Option<u128>,
+    ) -> Option<u128> {
+        let (mut added, mut removed) = (0_u128, 0_u128);
+        for nb_digits in self.nb_digits(range.0)..=self.nb_digits(range.1) {
+            for pattern_len in pattern_lens(nb_digits, mode) {
+                let Some(patterns) = self.patterns(range, nb_digits, pattern_len) else {
+                    continue;
+                };
+                let sign = match mode {
+                    Mode::Twice => -1,
+                    Mode::AtLeastTwice => mobius(nb_digits / pattern_len),
+                };
+                match sign {
+                    -1 => added = added.checked_add(total(&patterns)?)?,
+                    1 => removed = removed.checked_add(total(&patterns)?)?,
+                    _ => {}
+                }
+            }
+        }
+        Some(added - removed)
+    }
+
+    // The patterns are at most 64 bits, with at most 128 numbers of digits
+    // and a few divisors each, the counts always fit.
+    pub fn count(self, range: (u128, u128), mode: Mode) -> u128 {
+        self.total(range, mode, |patterns| Some(patterns.count()))
+            .expect("The counts fit in a u128")
+    }
+
+    // None when the sum, or one of the terms leading to it, doesn't fit in a u128.
+    pub fn sum(self, range: (u128, u128), mode: Mode) -> Option<u128> {
+        self.total(range, mode, Patterns::sum)
+    }
+
+    pub fn count_and_sum(self, range: (u128, u128), mode: Mode) -> Option<(u128, u128)> {
+        Some((self.count(range, mode), self.sum(range, mode)?))
+    }
+
+    pub fn invalid_ids(self, range: (u128, u128), mode: Mode) -> InvalidIds {
+        let by_nb_digits = (self.nb_digits(range.0)..=self.nb_digits(range.1))
+            .map(|nb_digits| {
+                pattern_lens(nb_digits, mode)
+                    .filter_map(|pattern_len| self.patterns(range, nb_digits, pattern_len))
+                    .collect()
+            })
+            .collect();
+        InvalidIds { by_nb_digits }
+    }
+
+    pub fn min(self, range: (u128, u128), mode: Mode) -> Option<InvalidId> {
+        self.invalid_ids(range, mode).next()
+    }
+
+    pub fn max(self, range: (u128, u128), mode: Mode) -> Option<InvalidId> {
+        self.invalid_ids(range, mode).next_back()
+    }
+
+    fn parse_number(self) -> impl Fn(&str) -> IResult<&str, u128> + Clone {
+        move |input| {
+            map_res(alphanumeric1, |digits| {
+                u128::from_str_radix(digits, self.radix)
+            })
+            .parse(input)
+        }
+    }
+
+    pub fn parse_ranges(self, data: &str) -> Result<Vec<(u128, u128)>, InputError> {
+        let ranges = parse_input(comma_separated(range(self.parse_number())), data)?;
+        if let Some((start, end)) = ranges.iter().find(|(start, end)| start > end) {
+            return Err(InputError::invalid(format!(
+                "Invalid range: {}-{}",
+                start, end
+            )));
+        }
+        Ok(ranges)
+    }
+}
+
+fn sum_too_large() -> InputError {
+    InputError::invalid("The sum of the invalid IDs doesn't fit")
+}
+
+// The sum of the invalid IDs of every range, written in the radix of ids.
+pub fn day_02_sum_with(data: &str, ids: RepeatedIds, mode: Mode) -> Result<u128, InputError> {
+    ids.parse_ranges(data)?
+        .iter()
+        .try_fold(0_u128, |total, &range| {
+            total.checked_add(ids.sum(range, mode)?)
+        })
+        .ok_or_else(sum_too_large)
+}
+
+// The invalid IDs of a decimal range, in increasing order.
+pub fn invalid_ids(range: (u128, u128), mode: Mode) -> InvalidIds {
+    RepeatedIds::DECIMAL.invalid_ids(range, mode)
+}
+
+// For each range, how many invalid IDs it has for both parts,
+// and the smallest and largest ones for part 2.
+pub fn day_02_explain(data: &str) -> Result<String, InputError> {
+    let ids = RepeatedIds::DECIMAL;
+    let mut table = vec![format!(
+        "{:>24} {:>8} {:>8} {:>24} {:>24}",
+        "range", "part 1", "part 2", "smallest", "largest"
+    )];
+    let describe = |invalid_id: Option<InvalidId>| {
+        invalid_id.map_or(String::new(), |invalid_id| {
+            format!("{}x{}", invalid_id.pattern, invalid_id.nb_repeats)
+        })
+    };
+    for range in ids.parse_ranges(data)? {
+        table.push(format!(
+            "{:>24} {:>8} {:>8} {:>24} {:>24}",
+            format!("{}-{}", range.0, range.1),
+            ids.count(range, Mode::Twice),
+            ids.count(range, Mode::AtLeastTwice),
+            describe(ids.min(range, Mode::AtLeastTwice)),
+            describe(ids.max(range, Mode::AtLeastTwice))
+        ));
+    }
+    Ok(table.join("\n"))
+}
+
+fn as_answer(sum: u128) -> Result<i64, InputError> {
+    i64::try_from(sum).map_err(|_| sum_too_large())
+}
+
+pub fn day_02_part_1(data: &str) -> Result<i64, InputError> {
+    as_answer(day_02_sum_with(data, RepeatedIds::DECIMAL, Mode::Twice)?)
+}
+
+pub fn day_02_part_2(data: &str) -> Result<i64, InputError> {
+    as_answer(day_02_sum_with(
+        data,
+        RepeatedIds::DECIMAL,
+        Mode::AtLeastTwice,
+    )?)
+}
+
+// Ranges of up to 10 digits and 100000 IDs, like the real ones.
+pub fn day_02_generate(rng: &mut Rng, size: usize) -> String {
+    render(
+        (0..size.max(1)).map(|_| {
+            let nb_digits = rng.between(1, 10) as u32;
+            let start = rng.between(10_u64.pow(nb_digits - 1), 10_u64.pow(nb_digits) - 1);
+            let end = start + rng.between(0, 100_000);
+            format!("{}-{}", start, end)
+        }),
+        ",",
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_core::generate::Rng;
+    use proptest::prelude::*;
+    use std::collections::HashSet;
+
+    const EXAMPLE: &str = "11-22,95-115,998-1012,1188511880-1188511890,222220-222224,
+1698522-1698528,446443-446449,38593856-38593862,565653-565659,
+824824821-824824827,2121212118-2121212124";
+
+    #[test]
+    fn test_day_02_part_1() {
+        assert_eq!(day_02_part_1(EXAMPLE), Ok(1227775554));
+    }
+
+    #[test]
+    fn test_day_02_part_2() {
+        assert_eq!(day_02_part_2(EXAMPLE), Ok(4174379265));
+    }
+
+    #[test]
+    fn test_day_02_part_2_minimal1() {
+        assert_eq!(day_02_part_2("11-22"), Ok(33));
+    }
+
+    #[test]
+    fn test_day_02_part_2_minimal2() {
+        assert_eq!(day_02_part_2("95-115"), Ok(210));
+    }
+
+    #[test]
+    fn test_day_02_part_2_minimal3() {
+        assert_eq!(day_02_part_2("998-1012"), Ok(2009));
+    }
+
+    #[test]
+    fn test_day_02_part_2_minimal4() {
+        assert_eq!(day_02_part_2("1188511880-1188511890"), Ok(1188511885));
+    }
+
+    #[test]
+    fn test_day_02_part_2_minimal5() {
+        assert_eq!(day_02_part_2("222220-222224"), Ok(222222));
+    }
+
+    #[test]
+    fn test_part1_optimisation_fail1() {
+        assert_eq!(day_02_part_1("825-1162"), Ok(2121));
+    }
+
+    #[test]
+    fn test_day_02_generate() {
+        let data = day_02_generate(&mut Rng::new(2), 20);
+        assert_eq!(data.split(',').count(), 20);
+        // the naive version checks every ID, so only the first ranges
+        let data = data.split(',').take(3).collect::<Vec<_>>().join(",");
+        assert_eq!(day_02_part_1(&data), Ok(naive_day_02(&data, Some(2))));
+        assert_eq!(day_02_part_2(&data), Ok(naive_day_02(&data, None)));
+    }
+
+    #[test]
+    fn test_day_02_invalid_input() {
+        assert!(day_02_part_1("0-10a").is_err());
+        assert!(day_02_part_2("22-11").is_err());
+        assert!(day_02_part_1("11-22,").is_err());
+    }
+
+    #[test]
+    fn test_day_02_huge_ranges() {
+        // every ID of up to 12 digits, the patterns are summed per length
+        let halves: i64 = (1..=6)
+            .map(|half_len| {
+                let divisor = 10_i64.pow(half_len);
+                (divisor / 10..divisor).sum::<i64>() * (divisor + 1)
+            })
+            .sum();
+        assert_eq!(day_02_part_1("1-999999999999"), Ok(halves));
+        assert!(day_02_part_2("1-999999999999").is_ok());
+        assert!(day_02_part_1("1-18446744073709551615").is_err());
+        assert_eq!(
+            day_02_part_2("18446744073709551615-18446744073709551615"),
+            Ok(0)
+        );
+    }
+
+    #[test]
+    fn test_day_02_zero_and_single_digits() {
+        assert_eq!(day_02_part_1("0-10"), Ok(0));
+        assert_eq!(day_02_part_2("0-9"), Ok(0));
+        assert_eq!(day_02_part_2("0-0,0-22"), Ok(33));
+    }
+
+    #[test]
+    fn test_patterns() {
+        let ids = RepeatedIds::DECIMAL;
+        let totals = |range, nb_digits, pattern_len| {
+            ids.patterns(range, nb_digits, pattern_len)
+                .map(|patterns| (patterns.count(), patterns.sum()))
+        };
+        // 111, 222, …, 999
+        assert_eq!(totals((100, 999), 3, 1), Some((9, Some(4995))));
+        assert_eq!(totals((1000, 9999), 4, 1), Some((9, Some(49995))));
+        assert_eq!(
+            totals((2000, 2222), 4, 2),
+            Some((3, Some(2020 + 2121 + 2222)))
+        );
+        assert_eq!(totals((5, 12), 2, 2), Some((3, Some(10 + 11 + 12))));
+        assert_eq!(totals((13, 21), 2, 1), None);
+        assert_eq!(
+            ids.count_and_sum((1000, 9999), Mode::AtLeastTwice),
+            Some((90, (10..100).sum::<u128>() * 101))
+        );
+    }
+
+    #[test]
+    fn test_invalid_ids() {
+        let ids: Vec<InvalidId> = invalid_ids((95, 1111), Mode::AtLeastTwice).collect();
+        assert_eq!(
+            ids.iter()
+                .map(|invalid_id| invalid_id.id)
+                .collect::<Vec<_>>(),
+            [99, 111, 222, 333, 444, 555, 666, 777, 888, 999, 1010, 1111]
+        );
+        // 1111 is 1 repeated 4 times rather than 11 twice
+        assert_eq!(
+            ids.last(),
+            Some(&InvalidId {
+                id: 1111,
+                pattern: 1,
+                nb_repeats: 4
+            })
+        );
+        let ids = RepeatedIds::DECIMAL;
+        assert_eq!(
+            ids.max((95, 1111), Mode::Twice),
+            Some(InvalidId {
+                id: 1111,
+                pattern: 11,
+                nb_repeats: 2
+            })
+        );
+        assert_eq!(ids.min((95, 1111), Mode::Twice).map(|min| min.id), Some(99));
+        assert_eq!(ids.min((100, 999), Mode::Twice), None);
+        assert_eq!(ids.max((0, 9), Mode::AtLeastTwice), None);
+        assert_eq!(ids.count((0, u128::MAX), Mode::Twice), 10_u128.pow(19) - 1);
+        // the iterator is lazy, the first ones come quickly
+        assert_eq!(
+            invalid_ids((0, u128::MAX), Mode::AtLeastTwice)
+                .nth(9)
+                .map(|invalid_id| invalid_id.id),
+            Some(111)
+        );
+    }
+
+    proptest! {
+        #[test]
+        fn test_invalid_ids_against_naive(
+            start in 0..10_000_000_u128,
+            len in 0..3_000_u128,
+            twice: bool,
+        ) {
+            let (range, mode) = ((start, start + len), if twice { Mode::Twice } else { Mode::AtLeastTwice });
+            let nb_repeats = twice.then_some(2);
+            let expected: Vec<u128> = (start..=start + len)
+                .filter(|&id| is_repeated(id as u64, nb_repeats))
+                .collect();
+            let ids: Vec<InvalidId> = invalid_ids(range, mode).collect();
+            prop_assert_eq!(ids.iter().map(|invalid_id| invalid_id.id).collect::<Vec<_>>(), expected);
+            for invalid_id in &ids {
+                let pattern = invalid_id.pattern.to_string();
+                prop_assert_eq!(pattern.repeat(invalid_id.nb_repeats as usize), invalid_id.id.to_string());
+                if !twice {
+                    prop_assert!(!is_repeated(invalid_id.pattern as u64, None));
+                }
+            }
+            let reversed: Vec<InvalidId> = invalid_ids(range, mode).rev().collect();
+            prop_assert!(reversed.iter().eq(ids.iter().rev()));
+            let decimal = RepeatedIds::DECIMAL;
+            prop_assert_eq!(decimal.count(range, mode), ids.len() as u128);
+            prop_assert_eq!(decimal.sum(range, mode), Some(ids.iter().map(|invalid_id| invalid_id.id).sum()));
+            prop_assert_eq!(decimal.min(range, mode), ids.first().copied());
+            prop_assert_eq!(decimal.max(range, mode), ids.last().copied());
+        }
+    }
+
+    #[test]
+    fn test_day_02_explain() {
+        let table = day_02_explain("95-115,1188511880-1188511890,1-9").unwrap();
+        let lines: Vec<&str> = table.lines().collect();
+        assert_eq!(lines.len(), 4);
+        assert!(lines[0].trim_start().starts_with("range"));
+        let row: Vec<&str> = lines[1].split_whitespace().collect();
+        assert_eq!(row, ["95-115", "1", "2", "9x2", "1x3"]);
+        let row: Vec<&str> = lines[2].split_whitespace().collect();
+        assert_eq!(
+            row,
+            ["1188511880-1188511890", "1", "1", "11885x2", "11885x2"]
+        );
+        let row: Vec<&str> = lines[3].split_whitespace().collect();
+        assert_eq!(row, ["1-9", "0", "0"]);
+        assert!(day_02_explain("9-1").is_err());
+    }
+
+    #[test]
+    fn test_other_radixes() {
+        assert!(RepeatedIds::new(1).is_err());
+        assert!(RepeatedIds::new(37).is_err());
+        let binary = RepeatedIds::new(2).unwrap();
+        // 11, 1010, 1111
+        assert_eq!(
+            binary.count_and_sum((0, 15), Mode::Twice),
+            Some((3, 3 + 10 + 15))
+        );
+        // and 111
+        assert_eq!(
+            binary.count_and_sum((0, 15), Mode::AtLeastTwice),
+            Some((4, 3 + 7 + 10 + 15))
+        );
+        let hexadecimal = RepeatedIds::new(16).unwrap();
+        assert_eq!(
+            day_02_sum_with("a-1f,FAFA-fafa", hexadecimal, Mode::Twice),
+            Ok(0x11 + 0xfafa)
+        );
+        assert!(day_02_sum_with("0-g", hexadecimal, Mode::Twice).is_err());
+    }
+
+    #[test]
+    fn test_day_02_u128() {
+        let ids = RepeatedIds::DECIMAL;
+        // 39 digits can't be a pattern repeated, 38 can
+        assert_eq!(ids.nb_digits(u128::MAX), 39);
+        let largest = repeat(10_u128.pow(19) - 1, 10_u128.pow(19), 2).unwrap();
+        assert_eq!(
+            ids.count_and_sum((largest - 1, largest + 1), Mode::AtLeastTwice),
+            Some((1, largest))
+        );
+        // 1000000000000 repeated 3 times
+        let smallest = 10_u128.pow(38) + 10_u128.pow(25) + 10_u128.pow(12);
+        assert_eq!(
+            ids.count_and_sum((10_u128.pow(38), smallest), Mode::AtLeastTwice),
+            Some((1, smallest))
+        );
+        assert_eq!(ids.count_and_sum((0, u128::MAX), Mode::Twice), None);
+        assert!(
+            day_02_sum_with(
+                "0-340282366920938463463374607431768211455",
+                ids,
+                Mode::Twice
+            )
+            .is_err()
+        );
+    }
+
+    #[test]
+    fn test_mobius() {
+        let mobius: Vec<i32> = (1..=12).map(mobius).collect();
+        assert_eq!(mobius, [1, -1, -1, 0, -1, 1, -1, 0, 0, 1, -1, 0]);
+    }
+
+    // The previous version, generating every repeated pattern
+    // and de-duplicating them with a set.
+    fn set_based_repeated(radix: u32, start: u128, end: u128) -> (u128, u128) {
+        let mut ids = HashSet::new();
+        let nb_digits_end = RepeatedIds::new(radix).unwrap().nb_digits(end);
+        for pattern_len in 1..=nb_digits_end / 2 {
+            let divisor = (radix as u128).pow(pattern_len);
+            for pattern in divisor / radix as u128..divisor {
+                for nb_repeats in 2..=nb_digits_end / pattern_len {
+                    let Some(id) = repeat(pattern, divisor, nb_repeats) else {
+                        break;
+                    };
+                    if id > end {
+                        break;
+                    }
+                    if id >= start {
+                        ids.insert(id);
+                    }
+                }
+            }
+        }
+        (ids.len() as u128, ids.iter().sum())
+    }
+
+    proptest! {
+        #[test]
+        fn test_repeated_against_set_based(
+            radix in prop_oneof![Just(10_u32), 2..=36_u32],
+            start in 0..10_000_000_000_u128,
+            len in prop_oneof![0..1_000_u128, 0..10_000_000_000_u128],
+        ) {
+            let ids = RepeatedIds::new(radix).unwrap();
+            let end = (start + len).min(9_999_999_999);
+            prop_assert_eq!(
+                ids.count_and_sum((start, end), Mode::AtLeastTwice),
+                Some(set_based_repeated(radix, start, end))
+            );
+        }
+    }
+
+    #[test]
+    fn test_repeat_beyond_u128() {
+        assert_eq!(repeat(12, 100, 3), Some(121212));
+        assert_eq!(
+            repeat(1_844_674_407, 10_000_000_000, 2),
+            Some(18_446_744_071_844_674_407)
+        );
+        assert_eq!(
+            repeat(3_402_823_669_209, 10_u128.pow(13), 3),
+            Some(340_282_366_920_934_028_236_692_093_402_823_669_209)
+        );
+        assert_eq!(repeat(3_402_823_669_210, 10_u128.pow(13), 3), None);
+    }
+
+    // The ID is a pattern repeated exactly nb_repeats times, or at least twice.
+    fn is_repeated(id: u64, nb_repeats: Option<usize>) -> bool {
+        let id = id.to_string();
+        (1..id.len()).any(|pattern_len| {
+            id.len().is_multiple_of(pattern_len)
+                && nb_repeats.is_none_or(|nb_repeats| id.len() / pattern_len == nb_repeats)
+                && id == id[..pattern_len].repeat(id.len() / pattern_len)
+        })
+    }
+
+    // Reference implementation, checking every ID of the ranges.
+    fn naive_day_02(data: &str, nb_repeats: Option<usize>) -> i64 {
+        let ranges = RepeatedIds::DECIMAL
+            .parse_ranges(data)
+            .expect("Failed to parse input data");
+        ranges
+            .iter()
+            .flat_map(|&(start, end)| start as u64..=end as u64)
+            .filter(|&id| is_repeated(id, nb_repeats))
+            .sum::<u64>() as i64
+    }
+
+    fn ranges() -> impl Strategy<Value = String> {
+        prop::collection::vec((0..10_000_000_u64, 0..3_000_u64), 1..4).prop_map(|ranges| {
+            render(
+                ranges
+                    .iter()
+                    .map(|(start, len)| format!("{}-{}", start, start + len)),
+                ",",
+            )
+        })
+    }
+
+    proptest! {
+        #[test]
+        fn test_day_02_against_naive(data in ranges()) {
+            prop_assert_eq!(day_02_part_1(&data), Ok(naive_day_02(&data, Some(2))));
+            prop_assert_eq!(day_02_part_2(&data), Ok(naive_day_02(&data, None)));
+        }
+    }
+}
@@ -0,0 +1,354 @@
+/*
+    easy day, first try on part 2
+
+    Both parts are now the same greedy choice with a different number of batteries,
+    with a stack instead of a scan of the bank per battery.
+    The Selector goes further than the puzzle: any number of batteries,
+    the smallest joltage instead of the largest, and banks rated in hexadecimal.
+*/
+
+use nom::{AsChar, character::complete::satisfy, combinator::map, multi::many1};
+use num_bigint::BigUint;
+
+use aoc_core::answer::Answer;
+use aoc_core::error::InputError;
+use aoc_core::generate::{Rng, render};
+use aoc_core::parsers::{lines, parse_input};
+
+// Every bank must have enough batteries to turn on.
+// The ratings are digits in the radix, 0-9 and a-z or A-Z.
+fn parse_input_data(
+    data: &str,
+    nb_batteries: usize,
+    radix: u32,
+) -> Result<Vec<Vec<u8>>, InputError> {
+    let banks = parse_input(
+        lines(many1(map(satisfy(|c| c.is_digit(radix)), |c| {
+            c.as_char().to_digit(radix).unwrap_or(0) as u8
+        }))),
+        data,
+    )?;
+    if let Some(index) = banks.iter().position(|bank| bank.len() < nb_batteries) {
+        return Err(InputError::invalid(format!(
+            "Bank {} has fewer than {} batteries",
+            index + 1,
+            nb_batteries
+        )));
+    }
+    Ok(banks)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Battery {
+    pub index: usize,
+    pub rating: u8,
+}
+
+// The puzzle wants the largest joltage, the smallest is the same problem upside down.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Goal {
+    Largest,
+    Smallest,
+}
+
+// The k batteries making the best number, in the order of the bank,
+// None if the bank has fewer than k batteries.
+// A battery is dropped when a better one comes after it, as long as
+// there are still enough batteries left. The stack stays sorted, worst last.
+// On a tie, the first battery is kept, leaving more choice after it.
+fn subsequence(bank: &[u8], k: usize, goal: Goal) -> Option<Vec<Battery>> {
+    let mut nb_drops = bank.len().checked_sub(k)?;
+    let mut stack: Vec<Battery> = Vec::with_capacity(bank.len());
+    for (index, &rating) in bank.iter().enumerate() {
+        while nb_drops > 0
+            && stack.last().is_some_and(|top| match goal {
+                Goal::Largest => top.rating < rating,
+                Goal::Smallest => top.rating > rating,
+            })
+        {
+            stack.pop();
+            nb_drops -= 1;
+        }
+        stack.push(Battery { index, rating });
+    }
+    // the drops left are at the end, where the worst ones are
+    stack.truncate(k);
+    Some(stack)
+}
+
+pub fn max_subsequence(bank: &[u8], k: usize) -> Option<Vec<Battery>> {
+    subsequence(bank, k, Goal::Largest)
+}
+
+pub fn min_subsequence(bank: &[u8], k: usize) -> Option<Vec<Battery>> {
+    subsequence(bank, k, Goal::Smallest)
+}
+
+// How to turn on the banks: how many batteries, rated in which radix,
+// and for the largest or smallest joltage. The joltages are big integers,
+// there is no limit on the number of batteries.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Selector {
+    nb_batteries: usize,
+    radix: u32,
+    goal: Goal,
+}
+
+impl Selector {
+    pub fn new(nb_batteries: usize, radix: u32, goal: Goal) -> Result<Self, InputError> {
+        if !(2..=36).contains(&radix) {
+            return Err(InputError::invalid(format!("Invalid radix: {}", radix)));
+        }
+        Ok(Selector {
+            nb_batteries,
+            radix,
+            goal,
+        })
+    }
+
+    pub fn parse(&self, data: &str) -> Result<Vec<Vec<u8>>, InputError> {
+        parse_input_data(data, self.nb_batteries, self.radix)
+    }
+
+    pub fn select(&self, bank: &[u8]) -> Option<Vec<Battery>> {
+        subsequence(bank, self.nb_batteries, self.goal)
+    }
+
+    // The ratings read as a number in the radix, leading zeros and all.
+    pub fn joltage(&self, batteries: &[Battery]) -> BigUint {
+        let ratings: Vec<u8> = batteries.iter().map(|battery| battery.rating).collect();
+        BigUint::from_radix_be(&ratings, self.radix).unwrap_or_default()
+    }
+
+    pub fn total_joltage(&self, data: &str) -> Result<BigUint, InputError> {
+        Ok(self
+            .parse(data)?
+            .iter()
+            .filter_map(|bank| self.select(bank))
+            .map(|batteries| self.joltage(&batteries))
+            .sum())
+    }
+}
+
+// Both parts with any selector, the answer is only big when it has to be.
+// Its text is the decimal total.
+pub fn day_03_with(data: &str, selector: Selector) -> Result<Answer, InputError> {
+    selector.total_joltage(data).map(Answer::from)
+}
+
+fn joltage(batteries: &[Battery]) -> i64 {
+    batteries
+        .iter()
+        .fold(0, |joltage, battery| joltage * 10 + battery.rating as i64)
+}
+
+// The parts are small enough for an i64.
+fn total_joltage(data: &str, nb_batteries: usize) -> Result<i64, InputError> {
+    let data = parse_input_data(data, nb_batteries, 10)?;
+    Ok(data
+        .iter()
+        .filter_map(|bank| max_subsequence(bank, nb_batteries))
+        .map(|batteries| joltage(&batteries))
+        .sum())
+}
+
+pub fn day_03_part_1(data: &str) -> Result<i64, InputError> {
+    total_joltage(data, 2)
+}
+
+pub fn day_03_part_2(data: &str) -> Result<i64, InputError> {
+    total_joltage(data, 12)
+}
+
+// Banks of 100 batteries, rated from 1 to 9.
+pub fn day_03_generate(rng: &mut Rng, size: usize) -> String {
+    render(
+        (0..size.max(1)).map(|_| render((0..100).map(|_| rng.between(1, 9)), "")),
+        "\n",
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_core::generate::Rng;
+    use proptest::prelude::*;
+
+    const EXAMPLE: &str = "987654321111111
+811111111111119
+234234234234278
+818181911112111";
+
+    #[test]
+    fn test_day_03_part_1() {
+        assert_eq!(day_03_part_1(EXAMPLE), Ok(357));
+    }
+
+    #[test]
+    fn test_day_03_part_2() {
+        assert_eq!(day_03_part_2(EXAMPLE), Ok(3121910778619));
+    }
+
+    #[test]
+    fn test_max_subsequence() {
+        let bank = [8, 1, 8, 1, 8, 1, 9, 1, 1];
+        let ratings = |k| {
+            max_subsequence(&bank, k)
+                .unwrap()
+                .iter()
+                .map(|battery| (battery.index, battery.rating))
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(ratings(3), [(6, 9), (7, 1), (8, 1)]);
+        // on a tie, the first battery, leaving more choice after it
+        assert_eq!(ratings(4), [(0, 8), (6, 9), (7, 1), (8, 1)]);
+        assert_eq!(ratings(5), [(0, 8), (2, 8), (6, 9), (7, 1), (8, 1)]);
+        assert_eq!(max_subsequence(&bank, 0), Some(vec![]));
+        assert_eq!(max_subsequence(&bank, 9).unwrap().len(), 9);
+        assert_eq!(max_subsequence(&bank, 10), None);
+    }
+
+    proptest! {
+        #[test]
+        fn test_max_subsequence_against_naive(
+            bank in prop::collection::vec(0..10_u8, 1..12),
+            k in 0..12_usize,
+        ) {
+            let Some(batteries) = max_subsequence(&bank, k) else {
+                prop_assert!(k > bank.len());
+                return Ok(());
+            };
+            prop_assert_eq!(batteries.len(), k);
+            prop_assert!(batteries.windows(2).all(|pair| pair[0].index < pair[1].index));
+            prop_assert!(batteries.iter().all(|battery| bank[battery.index] == battery.rating));
+            prop_assert_eq!(joltage(&batteries), naive_joltage(&bank, k, Goal::Largest));
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn test_min_subsequence_against_naive(
+            bank in prop::collection::vec(0..10_u8, 1..12),
+            k in 0..12_usize,
+        ) {
+            prop_assume!(k <= bank.len());
+            let batteries = min_subsequence(&bank, k).unwrap();
+            prop_assert_eq!(joltage(&batteries), naive_joltage(&bank, k, Goal::Smallest));
+        }
+    }
+
+    #[test]
+    fn test_selector() {
+        assert!(Selector::new(2, 1, Goal::Largest).is_err());
+        assert!(Selector::new(2, 37, Goal::Largest).is_err());
+
+        // the parts are special cases
+        for (nb_batteries, expected) in [(2, 357), (12, 3121910778619)] {
+            let selector = Selector::new(nb_batteries, 10, Goal::Largest).unwrap();
+            assert_eq!(
+                day_03_with(EXAMPLE, selector),
+                Ok(Answer::Integer(expected))
+            );
+        }
+
+        // every battery of the first bank, and beyond an i64
+        let selector = Selector::new(15, 10, Goal::Largest).unwrap();
+        assert_eq!(
+            day_03_with(EXAMPLE, selector).map(|answer| answer.text()),
+            Ok(
+                (987654321111111_u128 + 811111111111119 + 234234234234278 + 818181911112111)
+                    .to_string()
+            )
+        );
+        let bank = "9".repeat(40);
+        let selector = Selector::new(40, 10, Goal::Largest).unwrap();
+        assert_eq!(
+            day_03_with(&bank, selector).map(|answer| answer.text()),
+            Ok(bank.clone())
+        );
+        assert_eq!(
+            Selector::new(0, 10, Goal::Largest)
+                .unwrap()
+                .total_joltage(&bank),
+            Ok(BigUint::default())
+        );
+        assert!(day_03_with(&bank, Selector::new(41, 10, Goal::Largest).unwrap()).is_err());
+
+        let selector = Selector::new(3, 10, Goal::Smallest).unwrap();
+        assert_eq!(
+            day_03_with("987654321111111\n811111111111119", selector),
+            Ok(Answer::Integer(111 + 111))
+        );
+    }
+
+    #[test]
+    fn test_selector_hexadecimal() {
+        let selector = Selector::new(2, 16, Goal::Largest).unwrap();
+        assert_eq!(
+            day_03_with("1f0E\nA9", selector),
+            Ok(Answer::Integer(0xfe + 0xa9))
+        );
+        let selector = Selector::new(2, 16, Goal::Smallest).unwrap();
+        assert_eq!(
+            day_03_with("1f0E\nA9", selector),
+            Ok(Answer::Integer(0x0e + 0xa9))
+        );
+        assert!(day_03_with("1g", selector).is_err());
+        assert!(day_03_part_1("1f").is_err());
+    }
+
+    #[test]
+    fn test_day_03_generate() {
+        let data = day_03_generate(&mut Rng::new(3), 10);
+        assert_eq!(data.lines().count(), 10);
+        assert!(data.lines().all(|bank| bank.len() == 100));
+        assert_eq!(day_03_part_1(&data), Ok(naive_day_03(&data, 2)));
+    }
+
+    #[test]
+    fn test_day_03_invalid_input() {
+        assert_eq!(
+            day_03_part_1("9"),
+            Err(InputError::invalid("Bank 1 has fewer than 2 batteries"))
+        );
+        assert!(day_03_part_2("987654321111111\n98765").is_err());
+        assert!(day_03_part_1("98a").is_err());
+    }
+
+    // Reference implementation, trying every choice of nb_batteries batteries.
+    fn naive_joltage(bank: &[u8], nb_batteries: usize, goal: Goal) -> i64 {
+        if nb_batteries == 0 {
+            return 0;
+        }
+        let joltages = (0..=bank.len() - nb_batteries).map(|first| {
+            let rest = naive_joltage(&bank[first + 1..], nb_batteries - 1, goal);
+            bank[first] as i64 * 10_i64.pow(nb_batteries as u32 - 1) + rest
+        });
+        match goal {
+            Goal::Largest => joltages.max(),
+            Goal::Smallest => joltages.min(),
+        }
+        .unwrap()
+    }
+
+    fn naive_day_03(data: &str, nb_batteries: usize) -> i64 {
+        let banks = parse_input_data(data, nb_batteries, 10).expect("Failed to parse input data");
+        banks
+            .iter()
+            .map(|bank| naive_joltage(bank, nb_batteries, Goal::Largest))
+            .sum()
+    }
+
+    fn banks() -> impl Strategy<Value = String> {
+        prop::collection::vec(prop::collection::vec(0..10_u8, 12..16), 1..4)
+            .prop_map(|banks| render(banks.iter().map(|bank| render(bank, "")), "\n"))
+    }
+
+    proptest! {
+        #[test]
+        fn test_day_03_against_naive(data in banks()) {
+            prop_assert_eq!(day_03_part_1(&data), Ok(naive_day_03(&data, 2)));
+            prop_assert_eq!(day_03_part_2(&data), Ok(naive_day_03(&data, 12)));
+        }
+    }
+}
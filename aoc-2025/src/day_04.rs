@@ -0,0 +1,273 @@
+/*
+    Part 1: mostly playing with rust and ndarray
+    Part 2: relatively easy, wasted time trying to use mutable windows with
+    ndarray, without success. did it the old-fashioned way.
+    got reminded by reddit to use a queue for flood-fill algorithms.
+*/
+use ndarray::{Array2, s};
+use nom::{IResult, Parser, branch::alt, bytes::complete::tag, combinator::value};
+
+use aoc_core::error::InputError;
+use aoc_core::generate::Rng;
+use aoc_core::parsers::{grid, parse_input};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Cell {
+    Empty,
+    PaperRoll,
+}
+
+// implement to u8
+impl From<Cell> for u8 {
+    fn from(cell: Cell) -> Self {
+        match cell {
+            Cell::Empty => 0,
+            Cell::PaperRoll => 1,
+        }
+    }
+}
+
+fn parse_cell(input: &str) -> IResult<&str, Cell> {
+    alt((
+        value(Cell::Empty, tag(".")),
+        value(Cell::PaperRoll, tag("@")),
+    ))
+    .parse(input)
+}
+
+fn parse_input_data(data: &str) -> Result<Array2<Cell>, InputError> {
+    parse_input(grid(parse_cell), data)
+}
+
+fn pad(input: &Array2<Cell>, pad_width: usize, pad_value: Cell) -> Array2<Cell> {
+    // create the empty new
+    let mut new_array = Array2::from_elem(
+        (
+            input.nrows() + pad_width + pad_width,
+            input.ncols() + pad_width + pad_width,
+        ),
+        pad_value,
+    );
+
+    // copy the input into the center of the new array
+    let mut view = new_array.slice_mut(s![
+        pad_width..pad_width + input.nrows(),
+        pad_width..pad_width + input.ncols()
+    ]);
+    view.assign(input);
+    new_array
+}
+
+pub fn day_04_part_1(data: &str) -> Result<i64, InputError> {
+    let data = parse_input_data(data)?;
+
+    // Padding to avoid dealing with boundaries
+    let padded = pad(&data, 1, Cell::Empty);
+
+    Ok(padded
+        .windows((3, 3))
+        .into_iter()
+        .filter(|window| {
+            let center = window[(1, 1)];
+            if center != Cell::PaperRoll {
+                return false;
+            }
+            let sum = window.iter().fold(0, |acc, cell| acc + u8::from(*cell));
+            sum < 5
+        })
+        .count() as i64)
+}
+
+pub fn day_04_part_2(data: &str) -> Result<i64, InputError> {
+    let data = parse_input_data(data)?;
+    let padded = pad(&data, 1, Cell::Empty);
+
+    let mut grid = padded;
+    let mut count = 0_i64;
+
+    let mut queue: Vec<(usize, usize)> = Vec::with_capacity(grid.len());
+    // Check everywhere at least once
+    for row in 1..(grid.nrows() - 1) {
+        for col in 1..(grid.ncols() - 1) {
+            if grid[(row, col)] == Cell::PaperRoll {
+                queue.push((row, col));
+            }
+        }
+    }
+
+    while let Some((row, col)) = queue.pop() {
+        if grid[(row, col)] != Cell::PaperRoll {
+            continue;
+        }
+        let sum: u8 = u8::from(grid[(row - 1, col - 1)])
+            + u8::from(grid[(row - 1, col)])
+            + u8::from(grid[(row - 1, col + 1)])
+            + u8::from(grid[(row, col - 1)])
+            + u8::from(grid[(row, col + 1)])
+            + u8::from(grid[(row + 1, col - 1)])
+            + u8::from(grid[(row + 1, col)])
+            + u8::from(grid[(row + 1, col + 1)]);
+        if sum < 4 {
+            grid[(row, col)] = Cell::Empty;
+            count += 1;
+            // add neighbors to the queue
+            if grid[(row - 1, col - 1)] == Cell::PaperRoll {
+                queue.push((row - 1, col - 1));
+            }
+            if grid[(row - 1, col)] == Cell::PaperRoll {
+                queue.push((row - 1, col));
+            }
+            if grid[(row - 1, col + 1)] == Cell::PaperRoll {
+                queue.push((row - 1, col + 1));
+            }
+            if grid[(row, col - 1)] == Cell::PaperRoll {
+                queue.push((row, col - 1));
+            }
+            if grid[(row, col + 1)] == Cell::PaperRoll {
+                queue.push((row, col + 1));
+            }
+            if grid[(row + 1, col - 1)] == Cell::PaperRoll {
+                queue.push((row + 1, col - 1));
+            }
+            if grid[(row + 1, col)] == Cell::PaperRoll {
+                queue.push((row + 1, col));
+            }
+            if grid[(row + 1, col + 1)] == Cell::PaperRoll {
+                queue.push((row + 1, col + 1));
+            }
+        }
+    }
+
+    Ok(count)
+}
+
+// A square grid, with about two paper rolls for one empty cell.
+pub fn day_04_generate(rng: &mut Rng, size: usize) -> String {
+    let side = size.max(1);
+    (0..side)
+        .map(|_| {
+            (0..side)
+                .map(|_| if rng.chance(0.65) { '@' } else { '.' })
+                .collect::<String>()
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_core::generate::{Rng, render};
+    use proptest::prelude::*;
+
+    const EXAMPLE: &str = "..@@.@@@@.
+@@@.@.@.@@
+@@@@@.@.@@
+@.@@@@..@.
+@@.@@@@.@@
+.@@@@@@@.@
+.@.@.@.@@@
+@.@@@.@@@@
+.@@@@@@@@.
+@.@.@@@.@.";
+
+    #[test]
+    fn test_day_04_part_1() {
+        assert_eq!(day_04_part_1(EXAMPLE), Ok(13));
+    }
+
+    #[test]
+    fn test_day_04_part_2() {
+        assert_eq!(day_04_part_2(EXAMPLE), Ok(43));
+    }
+
+    #[test]
+    fn test_day_04_generate() {
+        let data = day_04_generate(&mut Rng::new(4), 50);
+        assert_eq!(data.lines().count(), 50);
+        assert!(day_04_part_1(&data).unwrap() <= day_04_part_2(&data).unwrap());
+    }
+
+    #[test]
+    fn test_day_04_invalid_input() {
+        assert!(day_04_part_1("..@\n.@").is_err());
+        assert!(day_04_part_2("..#").is_err());
+    }
+
+    fn nb_neighbours(rolls: &[Vec<bool>], row: usize, col: usize) -> usize {
+        let mut count = 0;
+        for neighbour_row in row.saturating_sub(1)..=row + 1 {
+            for neighbour_col in col.saturating_sub(1)..=col + 1 {
+                if (neighbour_row, neighbour_col) != (row, col)
+                    && rolls
+                        .get(neighbour_row)
+                        .and_then(|line| line.get(neighbour_col))
+                        .is_some_and(|&roll| roll)
+                {
+                    count += 1;
+                }
+            }
+        }
+        count
+    }
+
+    // Reference implementation, counting the neighbours of every cell,
+    // and removing all the accessible rolls at once until none is left.
+    fn naive_day_04(data: &str) -> (i64, i64) {
+        let mut rolls: Vec<Vec<bool>> = data
+            .lines()
+            .map(|line| line.chars().map(|c| c == '@').collect())
+            .collect();
+        let accessible = |rolls: &[Vec<bool>]| {
+            let mut accessible = Vec::new();
+            for (row, line) in rolls.iter().enumerate() {
+                for (col, &roll) in line.iter().enumerate() {
+                    if roll && nb_neighbours(rolls, row, col) < 4 {
+                        accessible.push((row, col));
+                    }
+                }
+            }
+            accessible
+        };
+        let part_1 = accessible(&rolls).len();
+        let mut part_2 = 0;
+        loop {
+            let removed = accessible(&rolls);
+            if removed.is_empty() {
+                break;
+            }
+            part_2 += removed.len();
+            for (row, col) in removed {
+                rolls[row][col] = false;
+            }
+        }
+        (part_1 as i64, part_2 as i64)
+    }
+
+    fn grids() -> impl Strategy<Value = String> {
+        (1..10_usize, 1..10_usize)
+            .prop_flat_map(|(nb_rows, nb_cols)| {
+                prop::collection::vec(
+                    prop::collection::vec(prop::bool::weighted(0.7), nb_cols),
+                    nb_rows,
+                )
+            })
+            .prop_map(|rows| {
+                render(
+                    rows.iter().map(|row| {
+                        render(row.iter().map(|&roll| if roll { '@' } else { '.' }), "")
+                    }),
+                    "\n",
+                )
+            })
+    }
+
+    proptest! {
+        #[test]
+        fn test_day_04_against_naive(data in grids()) {
+            let (part_1, part_2) = naive_day_04(&data);
+            prop_assert_eq!(day_04_part_1(&data), Ok(part_1));
+            prop_assert_eq!(day_04_part_2(&data), Ok(part_2));
+        }
+    }
+}
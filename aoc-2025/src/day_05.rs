@@ -0,0 +1,172 @@
+/*
+    Part 1 is very easy, let's do it super stupidly in O(n*m) and see what parts 2 is about,
+    before optimising both.
+
+    Part 2 is finally not too bad.
+
+    Then optimised part 1 with merging ranges and binary search.
+
+    The merging of ranges now lives in the interval module, as other days could use it.
+*/
+
+use nom::{IResult, Parser};
+
+use aoc_core::error::InputError;
+use aoc_core::generate::{Rng, render};
+use aoc_core::interval::IntervalSet;
+use aoc_core::parsers::{lines, parse_input, range, sections};
+use aoc_core::trace::trace;
+
+type Ranges = Vec<(u64, u64)>;
+fn parse_ranges(data: &str) -> IResult<&str, Ranges> {
+    lines(range(nom::character::complete::u64)).parse(data)
+}
+
+type Ingredients = Vec<u64>;
+fn parse_ingredients(data: &str) -> IResult<&str, Ingredients> {
+    lines(nom::character::complete::u64).parse(data)
+}
+
+fn parse_input_data(data: &str) -> Result<(Ranges, Ingredients), InputError> {
+    let sections = sections(data);
+    if sections.len() != 2 {
+        return Err(InputError::invalid(
+            "Expected ranges and ingredients sections",
+        ));
+    }
+    let ranges = parse_input(parse_ranges, sections[0])?;
+    if let Some((start, end)) = ranges.iter().find(|(start, end)| start > end) {
+        return Err(InputError::invalid(format!(
+            "Invalid range: {}-{}",
+            start, end
+        )));
+    }
+    let ingredients = parse_input(parse_ingredients, sections[1])?;
+    Ok((ranges, ingredients))
+}
+
+pub fn day_05_part_1(data: &str) -> Result<i64, InputError> {
+    let (ranges, ingredients) = parse_input_data(data)?;
+
+    // optimisations done after part 2
+    // reduce the range space
+    let nb_ranges = ranges.len();
+    let fresh_ranges: IntervalSet<u64> = ranges.into_iter().collect();
+    trace!(
+        Debug,
+        "{} ranges merged into {}",
+        nb_ranges,
+        fresh_ranges.nb_ranges()
+    );
+
+    Ok(ingredients
+        .iter()
+        .filter(|&&ingredient| fresh_ranges.contains(ingredient))
+        .count() as i64)
+}
+
+pub fn day_05_part_2(data: &str) -> Result<i64, InputError> {
+    let (ranges, _) = parse_input_data(data)?;
+
+    let fresh_ranges: IntervalSet<u64> = ranges.into_iter().collect();
+
+    i64::try_from(fresh_ranges.len())
+        .map_err(|_| InputError::invalid("Too many fresh IDs to fit in an i64"))
+}
+
+// As many ranges as ingredients, the ranges often overlapping.
+pub fn day_05_generate(rng: &mut Rng, size: usize) -> String {
+    const MAX_ID: u64 = 1_000_000_000_000_000;
+    let nb_ranges = size.max(1);
+    let ranges = render(
+        (0..nb_ranges).map(|_| {
+            let start = rng.between(1, MAX_ID);
+            let end = start + rng.between(0, MAX_ID / nb_ranges as u64);
+            format!("{}-{}", start, end)
+        }),
+        "\n",
+    );
+    let ingredients = render((0..size.max(1)).map(|_| rng.between(1, MAX_ID)), "\n");
+    format!("{}\n\n{}", ranges, ingredients)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_core::generate::Rng;
+    use proptest::prelude::*;
+
+    const EXAMPLE: &str = "3-5
+10-14
+16-20
+12-18
+
+1
+5
+8
+11
+17
+32";
+
+    #[test]
+    fn test_day_05_part_1() {
+        assert_eq!(day_05_part_1(EXAMPLE), Ok(3));
+    }
+
+    #[test]
+    fn test_day_05_part_2() {
+        assert_eq!(day_05_part_2(EXAMPLE), Ok(14));
+    }
+
+    #[test]
+    fn test_day_05_generate() {
+        let data = day_05_generate(&mut Rng::new(5), 100);
+        assert_eq!(data.lines().count(), 201);
+        let (ranges, ingredients) = parse_input_data(&data).expect("Failed to parse input data");
+        let nb_fresh = ingredients
+            .iter()
+            .filter(|&&id| ranges.iter().any(|&(start, end)| start <= id && id <= end))
+            .count();
+        assert_eq!(day_05_part_1(&data), Ok(nb_fresh as i64));
+    }
+
+    #[test]
+    fn test_day_05_invalid_input() {
+        assert!(day_05_part_1("3-5\n10-14").is_err());
+        assert!(day_05_part_2("5-3\n\n1").is_err());
+        assert!(day_05_part_1("3-5\n\n1\nfoo").is_err());
+        assert!(day_05_part_2("0-18446744073709551615\n\n1").is_err());
+    }
+
+    // Reference implementation, without merging the ranges.
+    fn naive_day_05(data: &str) -> (i64, i64) {
+        let (ranges, ingredients) = parse_input_data(data).expect("Failed to parse input data");
+        let is_fresh = |id: &u64| ranges.iter().any(|(start, end)| start <= id && id <= end);
+        let nb_fresh_ingredients = ingredients.iter().filter(|id| is_fresh(id)).count();
+        let max_id = ranges.iter().map(|(_, end)| *end).max().unwrap();
+        let nb_fresh_ids = (0..=max_id).filter(is_fresh).count();
+        (nb_fresh_ingredients as i64, nb_fresh_ids as i64)
+    }
+
+    fn inventory() -> impl Strategy<Value = String> {
+        (
+            prop::collection::vec((0..1_000_u64, 0..100_u64), 1..10),
+            prop::collection::vec(0..1_200_u64, 1..10),
+        )
+            .prop_map(|(ranges, ingredients)| {
+                let ranges = ranges
+                    .iter()
+                    .map(|(start, len)| format!("{}-{}", start, start + len));
+                format!("{}\n\n{}", render(ranges, "\n"), render(ingredients, "\n"))
+            })
+    }
+
+    proptest! {
+        #[test]
+        fn test_day_05_against_naive(data in inventory()) {
+            let (part_1, part_2) = naive_day_05(&data);
+            prop_assert_eq!(day_05_part_1(&data), Ok(part_1));
+            prop_assert_eq!(day_05_part_2(&data), Ok(part_2));
+        }
+    }
+}
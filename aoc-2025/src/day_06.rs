@@ -0,0 +1,411 @@
+/*
+    Another relatively easy day.
+
+    Trying to convert using maths, because string conversion
+    are slow. But the whitespaces are somewhat important,
+    so we will parse the grid as text, then rotate it,
+    and then parse it again.
+
+    The generated inputs found that part 1 didn't parse right aligned numbers
+    on the first line, nor trailing spaces before the operations.
+
+    A few large numbers multiplied together don't fit in a u64, so the grand total
+    is computed in u128 or big integers when it overflows.
+*/
+
+use ndarray::{Array2, Axis};
+use nom::{
+    AsChar, IResult, Parser,
+    branch::alt,
+    bytes::complete::tag,
+    character::complete::{line_ending, satisfy, space0, space1},
+    combinator::{map, value, verify},
+    multi::separated_list1,
+    sequence::{delimited, preceded, separated_pair},
+};
+
+use aoc_core::answer::Answer;
+use aoc_core::arithmetic::{
+    Arithmetic, Count, Overflow, checked_product, checked_sum, widening, with_arithmetic,
+};
+use aoc_core::error::InputError;
+use aoc_core::generate::Rng;
+use aoc_core::parsers::{grid, parse_input};
+use aoc_core::trace::trace;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Operation {
+    Add,
+    Multiply,
+}
+
+fn parse_operation(input: &str) -> IResult<&str, Operation> {
+    alt((
+        value(Operation::Add, tag("+")),
+        value(Operation::Multiply, tag("*")),
+    ))
+    .parse(input)
+}
+
+fn parse_operations(data: &str) -> IResult<&str, Vec<Operation>> {
+    separated_list1(space1, parse_operation).parse(data)
+}
+
+fn parse_number_grid(data: &str) -> IResult<&str, Array2<u64>> {
+    map(
+        // the first line can start with spaces too, when its numbers are right aligned
+        preceded(
+            space0,
+            verify(
+                separated_list1(
+                    (space0, line_ending, space0),
+                    separated_list1(space1, nom::character::complete::u64),
+                ),
+                |grid: &Vec<Vec<u64>>| grid.iter().all(|row| row.len() == grid[0].len()),
+            ),
+        ),
+        |grid| {
+            let nb_rows = grid.len();
+            let nb_cols = grid.first().map_or(0, |row| row.len());
+            Array2::from_shape_fn((nb_rows, nb_cols), |(row, col)| grid[row][col])
+        },
+    )
+    .parse(data)
+}
+
+fn parse_input_data_part1(data: &str) -> IResult<&str, (Array2<u64>, Vec<Operation>)> {
+    separated_pair(parse_number_grid, (space0, line_ending), parse_operations).parse(data)
+}
+
+fn check_nb_operations(nb_problems: usize, operations: &[Operation]) -> Result<(), InputError> {
+    if nb_problems != operations.len() {
+        return Err(InputError::invalid(format!(
+            "{} problems but {} operations",
+            nb_problems,
+            operations.len()
+        )));
+    }
+    Ok(())
+}
+
+type Problem = (Operation, Vec<u64>);
+
+fn solve<C: Count>((operation, numbers): &Problem) -> Result<C, Overflow> {
+    let numbers = numbers.iter().map(|&number| C::from(number));
+    match operation {
+        Operation::Add => checked_sum(numbers),
+        Operation::Multiply => checked_product(numbers),
+    }
+}
+
+fn grand_total<C: Count>(problems: &[Problem]) -> Result<C, Overflow> {
+    checked_sum(
+        problems
+            .iter()
+            .map(solve::<C>)
+            .collect::<Result<Vec<C>, Overflow>>()?,
+    )
+}
+
+fn grand_total_too_large() -> InputError {
+    InputError::invalid("The grand total doesn't fit with this arithmetic")
+}
+
+fn parse_problems_part_1(data: &str) -> Result<Vec<Problem>, InputError> {
+    let (number_grid, operations) = parse_input(parse_input_data_part1, data)?;
+    trace!(Trace, "Number grid:\n{:?}", number_grid);
+    trace!(Trace, "Operations: {:?}", operations);
+    check_nb_operations(number_grid.ncols(), &operations)?;
+
+    Ok(operations
+        .into_iter()
+        .zip(number_grid.columns())
+        .map(|(operation, column)| (operation, column.to_vec()))
+        .collect())
+}
+
+pub fn day_06_part_1(data: &str) -> Result<Answer, InputError> {
+    let problems = parse_problems_part_1(data)?;
+    Ok(widening!(grand_total(&problems)))
+}
+
+// Part 1 with a given arithmetic, failing when the grand total doesn't fit.
+pub fn day_06_part_1_with(data: &str, arithmetic: Arithmetic) -> Result<Answer, InputError> {
+    let problems = parse_problems_part_1(data)?;
+    with_arithmetic!(arithmetic, grand_total(&problems)).map_err(|_| grand_total_too_large())
+}
+
+fn parse_grid_as_chars(data: &str) -> IResult<&str, Array2<char>> {
+    grid(satisfy(|c: char| c.is_dec_digit() || c.is_space())).parse(data)
+}
+
+fn parse_input_data_part2(data: &str) -> IResult<&str, (Array2<char>, Vec<Operation>)> {
+    separated_pair(parse_grid_as_chars, line_ending, parse_operations).parse(data)
+}
+
+fn parse_rotated_input_data(data: &str) -> IResult<&str, Vec<Vec<u64>>> {
+    separated_list1(
+        (line_ending, space0, line_ending),
+        separated_list1(
+            line_ending,
+            delimited(space0, nom::character::complete::u64, space0),
+        ),
+    )
+    .parse(data)
+}
+
+fn parse_problems_part_2(data: &str) -> Result<Vec<Problem>, InputError> {
+    let (grid_chars, operations) = parse_input(parse_input_data_part2, data)?;
+    trace!(Trace, "Char grid:\n{:?}", grid_chars);
+    trace!(Trace, "Operations: {:?}", operations);
+
+    // rotate the grid chars 90 degrees anticlockwise
+    let mut rotated = grid_chars.t().to_owned();
+    rotated.invert_axis(Axis(0));
+    trace!(Trace, "Rotated char grid:\n{:?}", rotated);
+
+    // convert the grid char to a string
+    let string = rotated.rows().into_iter().fold(
+        String::with_capacity(grid_chars.len() + grid_chars.nrows()),
+        |mut acc, row| {
+            for c in row.iter() {
+                acc.push(*c);
+            }
+            acc.push('\n');
+            acc
+        },
+    );
+    trace!(Debug, "Rotated string:\n{}", string);
+
+    let rotated_number_grid = parse_input(parse_rotated_input_data, string.trim_start())
+        .map_err(|_| InputError::invalid("Failed to parse the numbers written in columns"))?;
+    trace!(Debug, "Rotated number grid: {:?}", rotated_number_grid);
+    check_nb_operations(rotated_number_grid.len(), &operations)?;
+
+    // the rotated problems are in reverse order
+    Ok(operations
+        .into_iter()
+        .zip(rotated_number_grid.into_iter().rev())
+        .collect())
+}
+
+pub fn day_06_part_2(data: &str) -> Result<Answer, InputError> {
+    let problems = parse_problems_part_2(data)?;
+    Ok(widening!(grand_total(&problems)))
+}
+
+// Part 2 with a given arithmetic, failing when the grand total doesn't fit.
+pub fn day_06_part_2_with(data: &str, arithmetic: Arithmetic) -> Result<Answer, InputError> {
+    let problems = parse_problems_part_2(data)?;
+    with_arithmetic!(arithmetic, grand_total(&problems)).map_err(|_| grand_total_too_large())
+}
+
+// Problems of four numbers, up to four digits wide and aligned either way.
+pub fn day_06_generate(rng: &mut Rng, size: usize) -> String {
+    const NB_NUMBERS: usize = 4;
+    let mut lines = vec![String::new(); NB_NUMBERS + 1];
+    for problem in 0..size.max(1) {
+        if problem > 0 {
+            for line in lines.iter_mut() {
+                line.push(' ');
+            }
+        }
+        let width = rng.between(1, 4) as usize;
+        // one of the numbers is as wide as the problem, so no column is empty
+        let mut nb_digits: Vec<usize> = (0..NB_NUMBERS)
+            .map(|index| {
+                if index == 0 {
+                    width
+                } else {
+                    rng.between(1, width as u64) as usize
+                }
+            })
+            .collect();
+        // part 2 parses each column as a number, the digits must not have gaps
+        nb_digits.sort_unstable();
+        if rng.chance(0.5) {
+            nb_digits.reverse();
+        }
+        let left_aligned = rng.chance(0.5);
+        for (line, nb_digits) in lines.iter_mut().zip(nb_digits) {
+            let number = rng.between(
+                10_u64.pow(nb_digits as u32 - 1),
+                10_u64.pow(nb_digits as u32) - 1,
+            );
+            if left_aligned {
+                line.push_str(&format!("{:<width$}", number));
+            } else {
+                line.push_str(&format!("{:>width$}", number));
+            }
+        }
+        let operation = if rng.chance(0.5) { '+' } else { '*' };
+        lines[NB_NUMBERS].push_str(&format!("{:<width$}", operation));
+    }
+    lines.join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_core::generate::{Rng, render};
+    use num_bigint::BigUint;
+    use proptest::prelude::*;
+
+    // Notice the x20 extra spaces at the end
+    // because the autoformatter kept removing trailing spaces from the EXAMPLE string
+    const EXAMPLE: &str = "123 328  51 64\x20
+ 45 64  387 23\x20
+  6 98  215 314
+*   +   *   +  ";
+
+    #[test]
+    fn test_day_06_part_1() {
+        assert_eq!(day_06_part_1(EXAMPLE), Ok(Answer::Integer(4277556)));
+    }
+
+    #[test]
+    fn test_day_06_part_2() {
+        assert_eq!(day_06_part_2(EXAMPLE), Ok(Answer::Integer(3263827)));
+    }
+
+    #[test]
+    fn test_day_06_generate() {
+        let data = day_06_generate(&mut Rng::new(6), 30);
+        assert_eq!(data.lines().count(), 5);
+        assert!(
+            data.lines()
+                .all(|line| line.len() == data.lines().next().unwrap().len())
+        );
+        assert!(day_06_part_1(&data).unwrap().as_i64().unwrap() > 0);
+        assert!(day_06_part_2(&data).unwrap().as_i64().unwrap() > 0);
+    }
+
+    #[test]
+    fn test_day_06_invalid_input() {
+        assert!(day_06_part_1("1 2\n3\n+ *").is_err());
+        assert!(day_06_part_1("1 2\n3 4\n+").is_err());
+        assert!(day_06_part_2("1  2\n3  4\n+ *").is_err());
+    }
+
+    #[test]
+    fn test_day_06_large_products() {
+        let data = "9999999999 9999\n9999999999 9\n* +";
+        assert!(day_06_part_1_with(data, Arithmetic::Checked).is_err());
+        assert_eq!(
+            day_06_part_1(data),
+            Ok(Answer::from(99999999980000000001_u128 + 10008))
+        );
+        let data = "9999999999999999999 9999999999999999999\n9999999999999999999 9999999999999999999\n9999999999999999999 9999999999999999999\n*                   *";
+        assert!(day_06_part_1_with(data, Arithmetic::Wide).is_err());
+        assert_eq!(
+            day_06_part_1(data).unwrap().to_string(),
+            "1999999999999999999400000000000000000059999999999999999998"
+        );
+        assert_eq!(
+            day_06_part_2_with(EXAMPLE, Arithmetic::Checked),
+            Ok(Answer::Integer(3263827))
+        );
+    }
+
+    // Reference implementation, cutting the worksheet on the blank columns
+    // and reading the numbers as text, in big integers.
+    fn naive_day_06(data: &str) -> (BigUint, BigUint) {
+        let lines: Vec<Vec<char>> = data.lines().map(|line| line.chars().collect()).collect();
+        let (operations, rows) = lines.split_last().unwrap();
+        let width = rows.iter().map(|row| row.len()).max().unwrap();
+        let is_blank = |col: usize| {
+            rows.iter()
+                .all(|row| row.get(col).is_none_or(|&c| c == ' '))
+        };
+        let number = |chars: Vec<char>| {
+            chars
+                .into_iter()
+                .filter(|c| c.is_ascii_digit())
+                .collect::<String>()
+                .parse::<BigUint>()
+                .unwrap()
+        };
+        let (mut part_1, mut part_2) = (BigUint::from(0_u8), BigUint::from(0_u8));
+        let mut start = 0;
+        while start < width {
+            let end = (start..width).find(|&col| is_blank(col)).unwrap_or(width);
+            let by_rows: Vec<BigUint> = rows
+                .iter()
+                .map(|row| number(row.iter().take(end).skip(start).copied().collect()))
+                .collect();
+            let by_columns: Vec<BigUint> = (start..end)
+                .map(|col| {
+                    number(
+                        rows.iter()
+                            .filter_map(|row| row.get(col))
+                            .copied()
+                            .collect(),
+                    )
+                })
+                .collect();
+            if operations[start] == '*' {
+                part_1 += by_rows.into_iter().product::<BigUint>();
+                part_2 += by_columns.into_iter().product::<BigUint>();
+            } else {
+                part_1 += by_rows.into_iter().sum::<BigUint>();
+                part_2 += by_columns.into_iter().sum::<BigUint>();
+            }
+            start = end + 1;
+        }
+        (part_1, part_2)
+    }
+
+    // (multiply, width, digit counts, digits, ascending counts, left aligned)
+    type Column = (bool, usize, Vec<usize>, Vec<Vec<u8>>, bool, bool);
+
+    fn problem(nb_rows: usize) -> impl Strategy<Value = Column> {
+        (1..6_usize).prop_flat_map(move |width| {
+            (
+                prop::bool::ANY,
+                Just(width),
+                prop::collection::vec(1..=width, nb_rows),
+                prop::collection::vec(prop::collection::vec(1..10_u8, width), nb_rows),
+                prop::bool::ANY,
+                prop::bool::ANY,
+            )
+        })
+    }
+
+    // Like the generated inputs, the digits of a column have no gaps,
+    // and one number of each problem is as wide as the problem.
+    fn worksheets() -> impl Strategy<Value = String> {
+        (1..5_usize)
+            .prop_flat_map(|nb_rows| prop::collection::vec(problem(nb_rows), 1..6))
+            .prop_map(|problems| {
+                let nb_rows = problems[0].2.len();
+                let mut lines = vec![Vec::new(); nb_rows + 1];
+                for (multiply, width, mut nb_digits, digits, ascending, left_aligned) in problems {
+                    nb_digits[0] = width;
+                    nb_digits.sort_unstable();
+                    if !ascending {
+                        nb_digits.reverse();
+                    }
+                    for (row, nb_digits) in nb_digits.into_iter().enumerate() {
+                        let number = render(&digits[row][..nb_digits], "");
+                        lines[row].push(if left_aligned {
+                            format!("{:<width$}", number)
+                        } else {
+                            format!("{:>width$}", number)
+                        });
+                    }
+                    let operation = if multiply { '*' } else { '+' };
+                    lines[nb_rows].push(format!("{:<width$}", operation));
+                }
+                render(lines.iter().map(|line| render(line, " ")), "\n")
+            })
+    }
+
+    proptest! {
+        #[test]
+        fn test_day_06_against_naive(data in worksheets()) {
+            let (part_1, part_2) = naive_day_06(&data);
+            prop_assert_eq!(day_06_part_1(&data), Ok(Answer::from(part_1)));
+            prop_assert_eq!(day_06_part_2(&data), Ok(Answer::from(part_2)));
+        }
+    }
+}
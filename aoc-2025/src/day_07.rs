@@ -0,0 +1,405 @@
+/*
+    Ugly code for part 1, but it works.
+
+    Part 2 is the fun one. Required to do part 1 again in a cleaner way first.
+
+    The commented-out representations of the beams became a proper visualisation.
+
+    The timelines double at every splitter they go through, so they are counted
+    in u64 first, and in u128 or big integers when that overflows.
+*/
+
+use ndarray::Array2;
+use nom::{IResult, Parser, branch::alt, bytes::complete::tag, combinator::value};
+use num_bigint::BigUint;
+use num_traits::ToPrimitive;
+
+use aoc_core::answer::Answer;
+use aoc_core::arithmetic::{Arithmetic, Count, Overflow, checked_sum, widening, with_arithmetic};
+use aoc_core::error::InputError;
+use aoc_core::generate::Rng;
+use aoc_core::parsers::{grid, parse_input};
+use aoc_core::trace::trace;
+use aoc_core::visualize::{Frame, Pixel, Rgb, Visualize};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Cell {
+    Empty,
+    BeamStart,
+    Splitter,
+}
+
+fn parse_cell(input: &str) -> IResult<&str, Cell> {
+    alt((
+        value(Cell::Empty, tag(".")),
+        value(Cell::BeamStart, tag("S")),
+        value(Cell::Splitter, tag("^")),
+    ))
+    .parse(input)
+}
+
+fn parse_input_data(data: &str) -> Result<Array2<Cell>, InputError> {
+    let grid = parse_input(grid(parse_cell), data)?;
+    let (nb_rows, nb_cols) = grid.dim();
+    if nb_rows < 2 || nb_cols < 2 {
+        return Err(InputError::invalid(
+            "We need at least two rows and two columns",
+        ));
+    }
+    Ok(grid)
+}
+
+pub fn day_07_part_1(data: &str) -> Result<i64, InputError> {
+    let grid = parse_input_data(data)?;
+    let nb_cols = grid.ncols();
+    let max_col_index = nb_cols - 1;
+
+    let mut nb_splits = 0;
+    let mut previous_row = vec![false; nb_cols];
+    for (col_index, cell) in grid.row(0).iter().enumerate() {
+        if cell == &Cell::BeamStart {
+            previous_row[col_index] = true;
+        }
+    }
+    for row in grid.rows() {
+        let mut current_row = vec![false; nb_cols];
+        for col_index in 0..=max_col_index {
+            current_row[col_index] = previous_row[col_index]
+                || (col_index > 0
+                    && (row[col_index - 1] == Cell::Splitter && previous_row[col_index - 1]))
+                || (col_index < max_col_index
+                    && (row[col_index + 1] == Cell::Splitter && previous_row[col_index + 1]));
+            if row[col_index] == Cell::Splitter && current_row[col_index] {
+                nb_splits += 1;
+                current_row[col_index] = false;
+            }
+        }
+        previous_row = current_row
+    }
+
+    trace!(Info, "Number of splits: {}", nb_splits);
+    Ok(nb_splits)
+}
+
+fn too_many_timelines() -> InputError {
+    InputError::invalid("Too many timelines to count with this arithmetic")
+}
+
+fn add_timelines<C: Count>(nb_timelines: &mut C, more: &C) -> Result<(), Overflow> {
+    *nb_timelines = nb_timelines.checked_add(more).ok_or(Overflow)?;
+    Ok(())
+}
+
+// Number of timelines going through each cell, row by row.
+fn propagate_timelines<C: Count>(grid: &Array2<Cell>) -> Result<Vec<Vec<C>>, Overflow> {
+    let (nb_rows, nb_cols) = grid.dim();
+    let max_col_index = nb_cols - 1;
+
+    let mut timelines: Vec<Vec<C>> = Vec::with_capacity(nb_rows);
+    let mut previous_row = vec![false; nb_cols];
+    let mut nb_timelines_previous_row = vec![C::zero(); nb_cols];
+    for row in grid.rows() {
+        let mut current_row = vec![false; nb_cols];
+        let mut nb_timelines_current_row = vec![C::zero(); nb_cols];
+        for col_index in 0..=max_col_index {
+            let nb_timelines = &nb_timelines_previous_row[col_index];
+            match (row[col_index], previous_row[col_index]) {
+                (Cell::BeamStart, false) => {
+                    current_row[col_index] = true;
+                    nb_timelines_current_row[col_index] = C::one();
+                }
+                (Cell::Empty, true) => {
+                    current_row[col_index] = true;
+                    add_timelines(&mut nb_timelines_current_row[col_index], nb_timelines)?;
+                }
+                (Cell::Splitter, true) => {
+                    // split the timelines to left and right
+                    if col_index > 0 {
+                        current_row[col_index - 1] = true;
+                        add_timelines(&mut nb_timelines_current_row[col_index - 1], nb_timelines)?;
+                    }
+                    if col_index < max_col_index {
+                        current_row[col_index + 1] = true;
+                        add_timelines(&mut nb_timelines_current_row[col_index + 1], nb_timelines)?;
+                    }
+                }
+                _ => {}
+            }
+        }
+        trace!(Trace, "Timelines: {:?}", nb_timelines_current_row);
+        previous_row = current_row;
+        timelines.push(nb_timelines_current_row.clone());
+        nb_timelines_previous_row = nb_timelines_current_row;
+    }
+
+    Ok(timelines)
+}
+
+fn total_timelines<C: Count>(grid: &Array2<Cell>) -> Result<C, Overflow> {
+    let timelines = propagate_timelines::<C>(grid)?;
+    checked_sum(timelines.last().expect("At least two rows").iter().cloned())
+}
+
+pub fn day_07_part_2(data: &str) -> Result<Answer, InputError> {
+    let grid = parse_input_data(data)?;
+    Ok(widening!(total_timelines(&grid)))
+}
+
+// Part 2 with a given arithmetic, failing when the timelines don't fit.
+pub fn day_07_part_2_with(data: &str, arithmetic: Arithmetic) -> Result<Answer, InputError> {
+    let grid = parse_input_data(data)?;
+    with_arithmetic!(arithmetic, total_timelines(&grid)).map_err(|_| too_many_timelines())
+}
+
+// A square manifold, with the splitters where the beams can reach them.
+// They get sparser as the manifold grows, or the timelines wouldn't fit in a u64.
+pub fn day_07_generate(rng: &mut Rng, size: usize) -> String {
+    let side = size.max(3);
+    let start = side / 2;
+    let density = (60.0 / side as f64).min(0.5);
+    (0..side)
+        .map(|row| {
+            (0..side)
+                .map(|col| {
+                    if row == 0 && col == start {
+                        'S'
+                    } else if row > 0
+                        && row % 2 == 0
+                        && (col + row / 2) % 2 == (start + 1) % 2
+                        && rng.chance(density)
+                    {
+                        '^'
+                    } else {
+                        '.'
+                    }
+                })
+                .collect::<String>()
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+struct TachyonManifold {
+    grid: Array2<Cell>,
+    // only the order of magnitude matters for the colours
+    timelines: Vec<Vec<f64>>,
+}
+
+impl Visualize for TachyonManifold {
+    // One frame per row reached by the beams, the brighter the more timelines.
+    fn frames(&self) -> Vec<Frame> {
+        const EMPTY: Pixel = Pixel::new('.', Rgb(16, 16, 32));
+        let (nb_rows, nb_cols) = self.grid.dim();
+        let max_timelines = self.timelines.iter().flatten().copied().fold(1.0, f64::max);
+
+        let mut frame = Frame::new(nb_cols, nb_rows, EMPTY);
+        for ((row, col), cell) in self.grid.indexed_iter() {
+            match cell {
+                Cell::Empty => {}
+                Cell::BeamStart => frame.set(row, col, Pixel::new('S', Rgb(255, 220, 0))),
+                Cell::Splitter => frame.set(row, col, Pixel::new('^', Rgb(160, 160, 160))),
+            }
+        }
+
+        let mut frames = Vec::with_capacity(nb_rows);
+        for (row, timelines) in self.timelines.iter().enumerate() {
+            for (col, &nb_timelines) in timelines.iter().enumerate() {
+                if nb_timelines > 0.0 && self.grid[(row, col)] == Cell::Empty {
+                    let intensity =
+                        64 + (191.0 * nb_timelines.ln_1p() / max_timelines.ln_1p()) as u8;
+                    frame.set(row, col, Pixel::new('|', Rgb(0, intensity, intensity)));
+                }
+            }
+            frames.push(frame.clone());
+        }
+        frames
+    }
+}
+
+pub fn day_07_visualize(data: &str) -> Result<Vec<Frame>, InputError> {
+    let grid = parse_input_data(data)?;
+    let timelines = propagate_timelines::<BigUint>(&grid)
+        .expect("Big integers don't overflow")
+        .iter()
+        .map(|row| {
+            row.iter()
+                .map(|nb_timelines| nb_timelines.to_f64().unwrap_or(f64::MAX))
+                .collect()
+        })
+        .collect();
+    Ok(TachyonManifold { grid, timelines }.frames())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_core::generate::{Rng, render};
+    use proptest::prelude::*;
+    use std::collections::BTreeSet;
+
+    const EXAMPLE: &str = ".......S.......
+...............
+.......^.......
+...............
+......^.^......
+...............
+.....^.^.^.....
+...............
+....^.^...^....
+...............
+...^.^...^.^...
+...............
+..^...^.....^..
+...............
+.^.^.^.^.^...^.
+...............";
+
+    #[test]
+    fn test_day_07_part_1() {
+        assert_eq!(day_07_part_1(EXAMPLE), Ok(21));
+    }
+
+    #[test]
+    fn test_day_07_part_2() {
+        assert_eq!(day_07_part_2(EXAMPLE), Ok(Answer::Integer(40)));
+    }
+
+    #[test]
+    fn test_day_07_visualize() {
+        let frames = day_07_visualize(EXAMPLE).unwrap();
+        assert_eq!(frames.len(), 16);
+        let last_frame = frames.last().unwrap().to_ascii();
+        assert!(last_frame.starts_with(".......S.......\n.......|.......\n"));
+    }
+
+    #[test]
+    fn test_day_07_generate() {
+        let data = day_07_generate(&mut Rng::new(7), 40);
+        assert_eq!(data.lines().count(), 40);
+        let nb_splits = day_07_part_1(&data).unwrap();
+        assert!(nb_splits > 0);
+        let nb_timelines = day_07_part_2(&data).unwrap().as_i64().unwrap();
+        assert!(nb_timelines > nb_splits);
+    }
+
+    #[test]
+    fn test_day_07_invalid_input() {
+        assert!(day_07_part_1("S").is_err());
+        assert!(day_07_part_2("..S..\n..x..").is_err());
+        assert!(day_07_visualize("..S\n.").is_err());
+    }
+
+    #[test]
+    fn test_day_07_too_many_timelines() {
+        // every splitter row doubles the timelines
+        let side = 140;
+        let data = (0..side)
+            .map(|row| {
+                (0..side)
+                    .map(|col| {
+                        if row == 0 && col == side / 2 {
+                            'S'
+                        } else if row > 0 && row % 2 == 0 && (col + row / 2) % 2 == 1 {
+                            '^'
+                        } else {
+                            '.'
+                        }
+                    })
+                    .collect::<String>()
+            })
+            .collect::<Vec<_>>()
+            .join("\n");
+        assert!(day_07_part_1(&data).is_ok());
+        assert!(day_07_part_2_with(&data, Arithmetic::Checked).is_err());
+        let wide = day_07_part_2_with(&data, Arithmetic::Wide).unwrap();
+        assert!(matches!(wide, Answer::BigInteger(_)));
+        assert_eq!(day_07_part_2_with(&data, Arithmetic::Big), Ok(wide.clone()));
+        assert_eq!(day_07_part_2(&data), Ok(wide));
+        assert!(day_07_visualize(&data).is_ok());
+    }
+
+    // Every timeline, followed one at a time from (row, col) down to the bottom.
+    fn naive_timelines(grid: &[Vec<char>], row: usize, col: usize) -> i64 {
+        if row == grid.len() {
+            return 1;
+        }
+        if grid[row][col] != '^' {
+            return naive_timelines(grid, row + 1, col);
+        }
+        // a beam leaving the manifold is lost
+        [col.checked_sub(1), Some(col + 1)]
+            .into_iter()
+            .flatten()
+            .filter(|&side| side < grid[row].len())
+            .map(|side| naive_timelines(grid, row + 1, side))
+            .sum()
+    }
+
+    // Reference implementation, moving the set of beams down one row at a time
+    // for the splits, and enumerating the timelines.
+    fn naive_day_07(data: &str) -> (i64, i64) {
+        let grid: Vec<Vec<char>> = data.lines().map(|line| line.chars().collect()).collect();
+        let start = grid[0].iter().position(|&c| c == 'S').unwrap();
+        let mut beams = BTreeSet::from([start]);
+        let mut nb_splits = 0;
+        for row in grid.iter().skip(1) {
+            let mut next_beams = BTreeSet::new();
+            for col in beams {
+                if row[col] == '^' {
+                    nb_splits += 1;
+                    next_beams.extend(col.checked_sub(1));
+                    next_beams.extend(Some(col + 1).filter(|&right| right < row.len()));
+                } else {
+                    next_beams.insert(col);
+                }
+            }
+            beams = next_beams;
+        }
+        (nb_splits, naive_timelines(&grid, 1, start))
+    }
+
+    // Like the generated manifolds, the splitters are on every other row and never
+    // next to each other, but they can be anywhere else, on the edges too.
+    fn manifolds() -> impl Strategy<Value = String> {
+        (2..12_usize, 2..12_usize)
+            .prop_flat_map(|(nb_rows, nb_cols)| {
+                (
+                    Just((nb_rows, nb_cols)),
+                    0..nb_cols,
+                    prop::collection::vec(prop::bool::weighted(0.6), nb_rows * nb_cols),
+                )
+            })
+            .prop_map(|((nb_rows, nb_cols), start, splitters)| {
+                render(
+                    (0..nb_rows).map(|row| {
+                        render(
+                            (0..nb_cols).map(|col| {
+                                if row == 0 && col == start {
+                                    'S'
+                                } else if row > 0
+                                    && row % 2 == 0
+                                    && (col + row / 2) % 2 == (start + 1) % 2
+                                    && splitters[row * nb_cols + col]
+                                {
+                                    '^'
+                                } else {
+                                    '.'
+                                }
+                            }),
+                            "",
+                        )
+                    }),
+                    "\n",
+                )
+            })
+    }
+
+    proptest! {
+        #[test]
+        fn test_day_07_against_naive(data in manifolds()) {
+            let (nb_splits, nb_timelines) = naive_day_07(&data);
+            prop_assert_eq!(day_07_part_1(&data), Ok(nb_splits));
+            prop_assert_eq!(day_07_part_2(&data), Ok(Answer::Integer(nb_timelines)));
+        }
+    }
+}
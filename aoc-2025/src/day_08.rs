@@ -0,0 +1,394 @@
+/*
+    Part 1 is a bit harder to do it fast.
+    I decided to wait for the end of the day to do it properly instead of rushing
+    a O(n^2) solution.
+
+    I first try with a variation of my favourite algorithm for spatial problems:
+    sweep and prune. because it's fast and simple. I don't expect to be remotely
+    close to the worst case.
+
+    It appears during testing that for the actual input data, the O(n²) solution
+    is actually slightly faster. I kept the slower sleep and prune because I find it cool
+    and it's not significantly slower, but yeah. That's a bit disappointing.
+
+    My initial plan was to use petgraph, but I got a spoiler by mistake and
+    learned that DSU would be the more appropriate data structure for this problem.
+
+    So I decided to not ignore the spoiler, and go with a DSU crate. disjoint looked
+    promising.
+
+    Part 2 was a bit more challenging to do fast. I did use a shortcut
+    by reusing code from the part 1 and hard-coding some shortcut threshold
+    with a failover to the full O(n²) solution if needed.
+
+    disjoin set unions seem like a nice fit.
+
+    I think this could be further optimised, but this sounds good enough for now.
+
+    --- Later, the sweep and prune and the hard-coded thresholds were replaced by
+    a k-d tree that gives the closest pairs in increasing distance order, lazily.
+    Part 2 doesn't need the full list of pairs anymore.
+
+    The circuits can be looked at with the visualisation runner,
+    instead of dumping the connections in the terminal.
+
+    The generated inputs showed that the product of the last x coordinates
+    overflowed an i32 when they are large enough, it's now done in i64.
+
+    The square distances are u128 in the spatial index, so any i32 coordinates are fine.
+*/
+
+use disjoint::DisjointSet;
+use nom::{IResult, Parser, combinator::map};
+
+use aoc_core::error::InputError;
+use aoc_core::generate::{Rng, render};
+use aoc_core::parsers::{lines, parse_input, point3};
+use aoc_core::spatial::KdTree;
+use aoc_core::trace::trace;
+use aoc_core::visualize::{Frame, Pixel, Rgb, Visualize};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct JunctionBox {
+    x: i32,
+    y: i32,
+    z: i32,
+}
+
+fn parse_junction_box(input: &str) -> IResult<&str, JunctionBox> {
+    map(point3(nom::character::complete::i32), |(x, y, z)| {
+        JunctionBox { x, y, z }
+    })
+    .parse(input)
+}
+
+fn parse_input_data(data: &str) -> Result<Vec<JunctionBox>, InputError> {
+    parse_input(lines(parse_junction_box), data)
+}
+
+fn build_spatial_index(junction_boxes: &[JunctionBox]) -> KdTree<3> {
+    KdTree::new(
+        junction_boxes
+            .iter()
+            .map(|jb| [jb.x as i64, jb.y as i64, jb.z as i64])
+            .collect(),
+    )
+}
+
+pub fn day_08_part_1(data: &str) -> Result<i64, InputError> {
+    let junction_boxes = parse_input_data(data)?;
+    trace!(Trace, "Parsed junction boxes: {:?}", junction_boxes);
+
+    // Quick hack to switch between example data and actual data
+    let nb_max_connections = if junction_boxes.len() >= 32 { 1000 } else { 10 };
+
+    let spatial_index = build_spatial_index(&junction_boxes);
+
+    let mut disjoint_set = DisjointSet::with_len(junction_boxes.len());
+    for (index_a, index_b, square_distance) in
+        spatial_index.closest_pairs().take(nb_max_connections)
+    {
+        trace!(
+            Debug,
+            "{:?} <-> {:?} : {}", junction_boxes[index_a], junction_boxes[index_b], square_distance
+        );
+        disjoint_set.join(index_a, index_b);
+    }
+
+    let mut sets = disjoint_set.sets();
+    let len = sets.len();
+    if len < 3 {
+        return Err(InputError::invalid(
+            "We need at least 3 sets of connected junction boxes",
+        ));
+    }
+    let k = len - 3;
+    // find the 3 largest sets
+    sets.select_nth_unstable_by(k, |a, b| a.len().cmp(&b.len()));
+    let sets = &sets[k..];
+    trace!(Info, "Largest circuits: {:?}", sets);
+
+    Ok((sets[0].len() * sets[1].len() * sets[2].len()) as i64)
+}
+
+pub fn day_08_part_2(data: &str) -> Result<i64, InputError> {
+    let junction_boxes = parse_input_data(data)?;
+    let nb_junction_boxes = junction_boxes.len();
+    if nb_junction_boxes < 2 {
+        return Err(InputError::invalid("We need at least 2 junction boxes"));
+    }
+
+    let spatial_index = build_spatial_index(&junction_boxes);
+
+    let mut disjoint_set = DisjointSet::with_len(nb_junction_boxes);
+    let mut current_count_of_sets = nb_junction_boxes;
+
+    for (nb_connections, (index_a, index_b, square_distance)) in
+        spatial_index.closest_pairs().enumerate()
+    {
+        if disjoint_set.join(index_a, index_b) {
+            current_count_of_sets -= 1;
+            trace!(
+                Debug,
+                "{:?} <-> {:?} : {}, {} circuits left",
+                junction_boxes[index_a],
+                junction_boxes[index_b],
+                square_distance,
+                current_count_of_sets
+            );
+        }
+        if current_count_of_sets == 1 {
+            trace!(
+                Info,
+                "Everything connected after {} connections",
+                nb_connections + 1
+            );
+            return Ok(junction_boxes[index_a].x as i64 * junction_boxes[index_b].x as i64);
+        }
+    }
+
+    unreachable!("Should have found the last connection");
+}
+
+// Junction boxes spread uniformly in a cube.
+pub fn day_08_generate(rng: &mut Rng, size: usize) -> String {
+    // part 1 makes 1000 connections when there are 32 boxes or more, and 10 otherwise,
+    // and needs 3 circuits at the end
+    let nb_junction_boxes = if size < 32 {
+        size.max(13)
+    } else {
+        size.max(1003)
+    };
+    render(
+        (0..nb_junction_boxes).map(|_| {
+            let [x, y, z] = [(); 3].map(|_| rng.between(0, 99_999));
+            format!("{},{},{}", x, y, z)
+        }),
+        "\n",
+    )
+}
+
+struct Playground {
+    junction_boxes: Vec<JunctionBox>,
+}
+
+impl Visualize for Playground {
+    // Top view of the junction boxes, coloured by circuit, after 0, 1, 2, 4, 8…
+    // connections and until everything is connected.
+    fn frames(&self) -> Vec<Frame> {
+        const WIDTH: usize = 96;
+        const HEIGHT: usize = 48;
+        const EMPTY: Pixel = Pixel::new(' ', Rgb(0, 0, 0));
+        let nb_junction_boxes = self.junction_boxes.len();
+        if nb_junction_boxes == 0 {
+            return Vec::new();
+        }
+
+        let (min_x, max_x, min_y, max_y) = self.junction_boxes.iter().fold(
+            (i32::MAX, i32::MIN, i32::MAX, i32::MIN),
+            |(min_x, max_x, min_y, max_y), jb| {
+                (
+                    min_x.min(jb.x),
+                    max_x.max(jb.x),
+                    min_y.min(jb.y),
+                    max_y.max(jb.y),
+                )
+            },
+        );
+        let to_cell = |value: i32, min: i32, max: i32, size: usize| {
+            let (value, min, max) = (value as i64, min as i64, max as i64);
+            ((value - min) * (size as i64 - 1) / (max - min).max(1)) as usize
+        };
+
+        let render = |disjoint_set: &DisjointSet| {
+            let mut frame = Frame::new(WIDTH, HEIGHT, EMPTY);
+            for (index, jb) in self.junction_boxes.iter().enumerate() {
+                let root = disjoint_set.root_of(index);
+                // a cheap hash of the circuit root, for stable colours between frames
+                let hash = (root as u64).wrapping_mul(0x9E37_79B9_7F4A_7C15);
+                let color = Rgb(
+                    64 + (hash >> 56) as u8 % 192,
+                    64 + (hash >> 48) as u8 % 192,
+                    64 + (hash >> 40) as u8 % 192,
+                );
+                let symbol = (b'a' + (root % 26) as u8) as char;
+                frame.set(
+                    to_cell(jb.y, min_y, max_y, HEIGHT),
+                    to_cell(jb.x, min_x, max_x, WIDTH),
+                    Pixel::new(symbol, color),
+                );
+            }
+            frame
+        };
+
+        let spatial_index = build_spatial_index(&self.junction_boxes);
+        let mut disjoint_set = DisjointSet::with_len(nb_junction_boxes);
+        let mut current_count_of_sets = nb_junction_boxes;
+        let mut frames = vec![render(&disjoint_set)];
+        let mut next_frame_at = 1;
+        for (nb_connections, (index_a, index_b, _square_distance)) in
+            spatial_index.closest_pairs().enumerate()
+        {
+            if disjoint_set.join(index_a, index_b) {
+                current_count_of_sets -= 1;
+            }
+            if nb_connections + 1 == next_frame_at || current_count_of_sets == 1 {
+                frames.push(render(&disjoint_set));
+                next_frame_at *= 2;
+            }
+            if current_count_of_sets == 1 {
+                break;
+            }
+        }
+        frames
+    }
+}
+
+pub fn day_08_visualize(data: &str) -> Result<Vec<Frame>, InputError> {
+    let junction_boxes = parse_input_data(data)?;
+    Ok(Playground { junction_boxes }.frames())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_core::generate::Rng;
+    use proptest::prelude::*;
+
+    const EXAMPLE: &str = "162,817,812
+57,618,57
+906,360,560
+592,479,940
+352,342,300
+466,668,158
+542,29,236
+431,825,988
+739,650,466
+52,470,668
+216,146,977
+819,987,18
+117,168,530
+805,96,715
+346,949,466
+970,615,88
+941,993,340
+862,61,35
+984,92,344
+425,690,689";
+
+    #[test]
+    fn test_day_08_part_1() {
+        assert_eq!(day_08_part_1(EXAMPLE), Ok(40));
+    }
+
+    #[test]
+    fn test_day_08_part_2() {
+        assert_eq!(day_08_part_2(EXAMPLE), Ok(25272));
+    }
+
+    #[test]
+    fn test_day_08_visualize() {
+        let frames = day_08_visualize(EXAMPLE).unwrap();
+        // 0, 1, 2, 4, 8, 16 connections, and the last one
+        assert_eq!(frames.len(), 7);
+        let symbols = |frame: &Frame| {
+            let mut symbols: Vec<char> = frame
+                .to_ascii()
+                .chars()
+                .filter(|c| c.is_alphabetic())
+                .collect();
+            symbols.sort_unstable();
+            symbols.dedup();
+            symbols.len()
+        };
+        assert_eq!(symbols(&frames[0]), 20);
+        assert_eq!(symbols(frames.last().unwrap()), 1);
+    }
+
+    #[test]
+    fn test_day_08_generate() {
+        let data = day_08_generate(&mut Rng::new(8), 20);
+        assert_eq!(data.lines().count(), 20);
+        let (part_1, part_2) = naive_day_08(&data);
+        assert_eq!(day_08_part_1(&data), Ok(part_1));
+        assert_eq!(day_08_part_2(&data), Ok(part_2));
+    }
+
+    #[test]
+    fn test_day_08_invalid_input() {
+        assert!(day_08_part_1("1,2,3\n4,5,6").is_err());
+        assert!(day_08_part_2("1,2,3").is_err());
+        assert!(day_08_part_2("1,2,3\n4,5").is_err());
+        assert!(day_08_part_2("1,2,3\n2147483648,0,0").is_err());
+        assert_eq!(day_08_part_2("1,2,3\n-2147483648,0,0"), Ok(-2147483648));
+    }
+
+    fn find(parents: &mut [usize], index: usize) -> usize {
+        if parents[index] != index {
+            parents[index] = find(parents, parents[index]);
+        }
+        parents[index]
+    }
+
+    // Reference implementation, sorting all the pairs.
+    // Ties are broken by index, like the k-d tree does.
+    fn naive_day_08(data: &str) -> (i64, i64) {
+        let junction_boxes = parse_input_data(data).expect("Failed to parse input data");
+        let nb_junction_boxes = junction_boxes.len();
+        let mut pairs = Vec::new();
+        for a in 0..nb_junction_boxes {
+            for b in a + 1..nb_junction_boxes {
+                let (jb_a, jb_b) = (junction_boxes[a], junction_boxes[b]);
+                let distance = [jb_a.x - jb_b.x, jb_a.y - jb_b.y, jb_a.z - jb_b.z]
+                    .iter()
+                    .map(|delta| (*delta as i64).pow(2))
+                    .sum::<i64>();
+                pairs.push((distance, a, b));
+            }
+        }
+        pairs.sort_unstable();
+
+        let mut parents: Vec<usize> = (0..nb_junction_boxes).collect();
+        let mut nb_circuits = nb_junction_boxes;
+        let mut part_1 = 0;
+        for (nb_connections, &(_, a, b)) in pairs.iter().enumerate() {
+            if nb_connections == 10 {
+                let mut sizes = vec![0; nb_junction_boxes];
+                for index in 0..nb_junction_boxes {
+                    sizes[find(&mut parents, index)] += 1;
+                }
+                sizes.sort_unstable_by(|a, b| b.cmp(a));
+                part_1 = sizes[0] * sizes[1] * sizes[2];
+            }
+            let (root_a, root_b) = (find(&mut parents, a), find(&mut parents, b));
+            if root_a != root_b {
+                parents[root_a] = root_b;
+                nb_circuits -= 1;
+                if nb_circuits == 1 {
+                    let part_2 = junction_boxes[a].x as i64 * junction_boxes[b].x as i64;
+                    return (part_1, part_2);
+                }
+            }
+        }
+        unreachable!("Everything is connected in the end")
+    }
+
+    // Small coordinates, so there are duplicated points and equal distances.
+    fn junction_boxes() -> impl Strategy<Value = String> {
+        prop::collection::vec((0..40_i32, 0..40_i32, 0..40_i32), 14..32).prop_map(|points| {
+            render(
+                points.iter().map(|(x, y, z)| format!("{},{},{}", x, y, z)),
+                "\n",
+            )
+        })
+    }
+
+    proptest! {
+        #[test]
+        fn test_day_08_against_naive(data in junction_boxes()) {
+            let (part_1, part_2) = naive_day_08(&data);
+            prop_assert_eq!(day_08_part_1(&data), Ok(part_1));
+            prop_assert_eq!(day_08_part_2(&data), Ok(part_2));
+        }
+    }
+}
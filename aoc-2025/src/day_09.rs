@@ -0,0 +1,764 @@
+/*
+    Part 1 is pretty easy. The O(n²) obvious solution was slightly optimised with
+    a sort on one axis first, that is my classic trick to speed up such problems.
+
+    I thought about solving part 2 quick&dirty first, but the grid is relatively big.
+    I think it's more about detecting whether we are crossing a closed path kind of algorithm,
+    but I'm not sure I know the algorithm yet.
+
+    I didn't want spoilers, and I noticed that many people were struggling with part 2 based on
+    the stats page.
+
+    I decided to reduce the problem size first, hoping that would help.
+
+    It's pretty slow. It works, but it's pretty slow. About 100ms on my M1 macbook pro.
+    I added rayon to parallelise the area computations, which helps a bit, but it's still not great.
+
+    -- After solving the problem myself, I found the mipmap tip on the subreddit.
+    This is exactly what I needed to speed up the flood-fill check.
+
+    Now, this is fast enough. About 6ms on my M1 macbook pro.
+
+    --- Browsing the reddit, I found test cases that fail.
+    I added them, but they are currently ignored and I don't plan to
+    fix the algorithm to support them.
+
+    The main issue is the flood fill approach, as I didn't implement a system
+    to detect holes/lakes inside the shape. Too bad.
+
+    The other issue is the coordinates compression not handling well rectangles
+    touching each other on their sides. A better coordinate compression algorithm
+    would be a lot more complex.
+
+    Overall, I think the coordinates compression + flood fill is perhaps not the
+    right solution for a generic algorithm, as it misses some cases. However,
+    it works for the input data and it's pretty fast, so it will stay as is for now.
+
+    The property tests against a naive implementation found that part 1 computed
+    the width wrong when the second point is on the left of the first one.
+    The largest area of the example was still right, so it went unnoticed.
+
+    The compressed grids can be rendered with the visualisation runner, it's easier
+    to see what's going on than with the old display_grid function.
+
+    The coordinates are kept under 2^31, so the areas fit in an i64.
+*/
+
+use std::collections::{HashMap, hash_map::Entry};
+
+use ndarray::{Array2, s};
+use nom::{IResult, Parser};
+
+use aoc_core::error::InputError;
+use aoc_core::generate::{Rng, render};
+use aoc_core::parallel::prelude::*;
+use aoc_core::parsers::{lines, parse_input, point2};
+use aoc_core::trace::trace;
+use aoc_core::visualize::{Frame, Pixel, Rgb, Visualize};
+
+const MAX_COORDINATE: u64 = 1 << 31;
+
+fn parse_points(data: &str) -> IResult<&str, Vec<(u64, u64)>> {
+    lines(point2(nom::character::complete::u64).map(|(a, b)| (b, a))).parse(data)
+}
+
+fn parse_input_data(data: &str) -> Result<Vec<(u64, u64)>, InputError> {
+    let points = parse_input(parse_points, data)?;
+    if let Some((row, col)) = points
+        .iter()
+        .find(|(row, col)| *row >= MAX_COORDINATE || *col >= MAX_COORDINATE)
+    {
+        return Err(InputError::invalid(format!(
+            "Red tile too far away: {},{}",
+            col, row
+        )));
+    }
+    Ok(points)
+}
+
+pub fn day_09_part_1(data: &str) -> Result<i64, InputError> {
+    let points = parse_input_data(data)?;
+    trace!(Trace, "Parsed {:?} points", points);
+
+    // Convert to i64 for easier computations
+    let mut sorted_points = points
+        .iter()
+        .map(|(row, col)| (*row as i64, *col as i64))
+        .collect::<Vec<_>>();
+    // Sort on one axis to speed up slightly the computations
+    sorted_points.sort_unstable_by_key(|(row, _col)| *row);
+
+    let mut max_area = 0_i64;
+    for (index_a, (row_a, col_a)) in sorted_points.iter().enumerate() {
+        for (row_b, col_b) in sorted_points.iter().skip(index_a + 1) {
+            let area = (row_b - row_a + 1) * ((col_b - col_a).abs() + 1);
+            if area > max_area {
+                trace!(
+                    Trace,
+                    "New max area {} with points ({},{}) and ({},{})",
+                    area,
+                    row_a,
+                    col_a,
+                    row_b,
+                    col_b
+                );
+                max_area = area;
+            }
+        }
+    }
+    Ok(max_area)
+}
+
+// A loop made of vertical bars side by side, each bar overlapping the next one.
+// Two distinct rows or columns of red tiles are never next to each other,
+// as the coordinates compression doesn't handle that.
+pub fn day_09_generate(rng: &mut Rng, size: usize) -> String {
+    let nb_bars = (size / 4).max(1);
+    let max_unit = (2 * nb_bars as u64).max(3);
+    let step = (100_000 / max_unit).max(2);
+
+    let mut cols = vec![0];
+    for _ in 0..nb_bars {
+        cols.push(cols.last().unwrap() + rng.between(1, 3));
+    }
+    let top = max_unit - rng.below(2) as u64;
+    let mut bars = vec![(rng.between(0, top - 2), top)];
+    while bars.len() < nb_bars {
+        let (bottom, top) = *bars.last().unwrap();
+        let next_top = loop {
+            let next_top = rng.between((bottom + 1).max(2), max_unit);
+            if next_top != top {
+                break next_top;
+            }
+        };
+        let next_bottom = loop {
+            let next_bottom = rng.between(0, top.min(next_top).min(max_unit - 1) - 1);
+            if next_bottom != bottom {
+                break next_bottom;
+            }
+        };
+        bars.push((next_bottom, next_top));
+    }
+
+    // along the tops from left to right, then back along the bottoms
+    let mut points = vec![(cols[0], bars[0].0)];
+    for (index, &(_, top)) in bars.iter().enumerate() {
+        points.push((cols[index], top));
+        points.push((cols[index + 1], top));
+    }
+    for (index, &(bottom, _)) in bars.iter().enumerate().rev() {
+        points.push((cols[index + 1], bottom));
+        if index > 0 {
+            points.push((cols[index], bottom));
+        }
+    }
+    render(
+        points
+            .iter()
+            .map(|(col, row)| format!("{},{}", 1 + col * step, 1 + row * step)),
+        "\n",
+    )
+}
+
+// The floor with compressed coordinates, every distinct row and column of a red tile
+// mapped to an odd index, so there is always an empty line between two of them.
+struct CompressedFloor {
+    rows_index_map: HashMap<u64, usize>,
+    cols_index_map: HashMap<u64, usize>,
+    // the loop of red and green tiles
+    outline: Array2<bool>,
+    // the outline and everything inside it
+    filled: Array2<bool>,
+}
+
+fn compress_floor(points: &[(u64, u64)]) -> CompressedFloor {
+    let (mut rows, mut cols): (Vec<u64>, Vec<u64>) = points.iter().cloned().unzip();
+    rows.sort_unstable();
+    cols.sort_unstable();
+
+    let mut rows_index_map: HashMap<u64, usize> = HashMap::with_capacity(rows.len());
+    let mut cols_index_map: HashMap<u64, usize> = HashMap::with_capacity(cols.len());
+
+    let mut index = 1;
+    for row in rows.iter().cloned() {
+        if let Entry::Vacant(e) = rows_index_map.entry(row) {
+            e.insert(index);
+            index += 2;
+        }
+    }
+    let max_row_index = index - 2;
+
+    index = 1;
+    for col in cols.iter().cloned() {
+        if let Entry::Vacant(e) = cols_index_map.entry(col) {
+            e.insert(index);
+            index += 2;
+        }
+    }
+    let max_col_index = index - 2;
+
+    let nb_rows = (max_row_index + 2).next_power_of_two();
+    let nb_cols = (max_col_index + 2).next_power_of_two();
+
+    let mut grid = Array2::<bool>::default((nb_rows, nb_cols));
+
+    for (prev, next) in points
+        .iter()
+        .zip(points.iter().cycle().skip(1))
+        .take(points.len())
+    {
+        let (prev_row, prev_col) = prev;
+        let (next_row, next_col) = next;
+        let prev_row_index = *rows_index_map.get(prev_row).unwrap();
+        let prev_col_index = *cols_index_map.get(prev_col).unwrap();
+        let next_row_index = *rows_index_map.get(next_row).unwrap();
+        let next_col_index = *cols_index_map.get(next_col).unwrap();
+
+        let mut view = grid.slice_mut(s![
+            prev_row_index.min(next_row_index)..=prev_row_index.max(next_row_index),
+            prev_col_index.min(next_col_index)..=prev_col_index.max(next_col_index)
+        ]);
+        view.fill(true);
+    }
+
+    let mut filled_grid = Array2::<bool>::from_elem((nb_rows, nb_cols), true);
+
+    // flood fill from (0,0)
+    let mut stack = vec![(0_usize, 0_usize)];
+    while let Some((row, col)) = stack.pop() {
+        if !filled_grid[(row, col)] || grid[(row, col)] {
+            continue;
+        }
+        filled_grid[(row, col)] = false;
+        if row > 0 {
+            stack.push((row - 1, col));
+        }
+        if row + 1 < nb_rows {
+            stack.push((row + 1, col));
+        }
+        if col > 0 {
+            stack.push((row, col - 1));
+        }
+        if col + 1 < nb_cols {
+            stack.push((row, col + 1));
+        }
+    }
+
+    CompressedFloor {
+        rows_index_map,
+        cols_index_map,
+        outline: grid,
+        filled: filled_grid,
+    }
+}
+
+impl Visualize for CompressedFloor {
+    // The outline first, then the filled area.
+    fn frames(&self) -> Vec<Frame> {
+        const OUTSIDE: Pixel = Pixel::new('.', Rgb(24, 24, 24));
+        const OUTLINE: Pixel = Pixel::new('#', Rgb(220, 40, 40));
+        const INSIDE: Pixel = Pixel::new('X', Rgb(40, 180, 40));
+        let (nb_rows, nb_cols) = self.outline.dim();
+
+        let mut outline_frame = Frame::new(nb_cols, nb_rows, OUTSIDE);
+        let mut filled_frame = Frame::new(nb_cols, nb_rows, OUTSIDE);
+        for ((row, col), &is_outline) in self.outline.indexed_iter() {
+            if is_outline {
+                outline_frame.set(row, col, OUTLINE);
+                filled_frame.set(row, col, OUTLINE);
+            } else if self.filled[(row, col)] {
+                filled_frame.set(row, col, INSIDE);
+            }
+        }
+        vec![outline_frame, filled_frame]
+    }
+}
+
+pub fn day_09_visualize(data: &str) -> Result<Vec<Frame>, InputError> {
+    let points = parse_input_data(data)?;
+    Ok(compress_floor(&points).frames())
+}
+
+pub fn day_09_part_2(data: &str) -> Result<i64, InputError> {
+    let points = parse_input_data(data)?;
+
+    let CompressedFloor {
+        rows_index_map,
+        cols_index_map,
+        filled: filled_grid,
+        ..
+    } = compress_floor(&points);
+    let (nb_rows, nb_cols) = filled_grid.dim();
+
+    // build a mipmap of blocks representing block_size*block_size areas that are fully filled
+    let block_size = 8;
+    let nb_block_rows = nb_rows.div_ceil(block_size);
+    let nb_block_cols = nb_cols.div_ceil(block_size);
+    let mut mipmap_grid = Array2::<bool>::from_elem((nb_block_rows, nb_block_cols), false);
+    for row in 0..nb_block_rows {
+        for col in 0..nb_block_cols {
+            let row_start = row * block_size;
+            let col_start = col * block_size;
+            //if row_start >= nb_rows || col_start >= nb_cols {
+            //    continue;
+            //}
+            let row_end = (row_start + block_size).min(nb_rows);
+            let col_end = (col_start + block_size).min(nb_cols);
+            let view = filled_grid.slice(s![row_start..row_end, col_start..col_end]);
+            if view.iter().all(|cell| *cell) {
+                mipmap_grid[(row, col)] = true;
+            }
+        }
+    }
+    trace!(
+        Debug,
+        "Mipmap grid of {:?} blocks created, {} fully filled",
+        mipmap_grid.dim(),
+        mipmap_grid.iter().filter(|cell| **cell).count()
+    );
+
+    let mut sorted_points = points
+        .iter()
+        .map(|(row, col)| (*row as i64, *col as i64))
+        .collect::<Vec<_>>();
+    // Sort on one axis to speed up slightly the computations
+    sorted_points.sort_unstable_by_key(|(row, _col)| *row);
+
+    //let mut max_area = 0_i64;
+    //for (index_a, (row_a, col_a)) in sorted_points.iter().enumerate() {
+    Ok(sorted_points
+        .iter()
+        .enumerate()
+        .par_bridge()
+        .map(|(index_a, (row_a, col_a))| {
+            let mut max_area = 0_i64;
+            'outer: for (row_b, col_b) in sorted_points.iter().skip(index_a + 1) {
+                //for (row_b, col_b) in sorted_points.iter() {
+                let height = (row_b - row_a).abs() + 1;
+                let width = (col_b - col_a).abs() + 1;
+                let area = height * width;
+                //if area > max_area {
+                if area > max_area {
+                    // we have a potential area, let's check if it only contains true in the filled_grid
+                    let row_a_index = *rows_index_map.get(&(*row_a as u64)).unwrap();
+                    let col_a_index = *cols_index_map.get(&(*col_a as u64)).unwrap();
+                    let row_b_index = *rows_index_map.get(&(*row_b as u64)).unwrap();
+                    let col_b_index = *cols_index_map.get(&(*col_b as u64)).unwrap();
+
+                    let row_start = row_a_index.min(row_b_index);
+                    let row_end = row_a_index.max(row_b_index);
+                    let col_start = col_a_index.min(col_b_index);
+                    let col_end = col_a_index.max(col_b_index);
+
+                    //let view = filled_grid.slice(s![row_start..=row_end, col_start..=col_end]);
+                    //if view.iter().all(|cell| *cell) {
+
+                    // check the mipmap first and then the filled_grid if needed
+                    let mipmap_row_start = row_start / block_size;
+                    let mipmap_row_end = row_end / block_size;
+                    let mipmap_col_start = col_start / block_size;
+                    let mipmap_col_end = col_end / block_size;
+
+                    for mipmap_row in mipmap_row_start..=mipmap_row_end {
+                        for mipmap_col in mipmap_col_start..=mipmap_col_end {
+                            if !mipmap_grid[(mipmap_row, mipmap_col)] {
+                                // not fully filled block
+                                // let's check the filled grid for the right area (not the whole block)
+                                let actual_row_start = row_start.max(mipmap_row * block_size);
+                                let actual_row_end = ((mipmap_row + 1) * block_size - 1)
+                                    .min(row_end)
+                                    .min(nb_rows - 1);
+                                let actual_col_start = col_start.max(mipmap_col * block_size);
+                                let actual_col_end = ((mipmap_col + 1) * block_size - 1)
+                                    .min(col_end)
+                                    .min(nb_cols - 1);
+                                // sometimes we go out of bounds
+                                /*if actual_row_start >= nb_rows || actual_col_start >= nb_cols {
+                                    continue;
+                                }*/
+
+                                let view = filled_grid.slice(s![
+                                    actual_row_start..=actual_row_end,
+                                    actual_col_start..=actual_col_end
+                                ]);
+                                if !view.iter().all(|cell| *cell) {
+                                    trace!(
+                                        Trace,
+                                        "Rejected area {} due to grid coords ({},{}) and ({},{})",
+                                        area,
+                                        row_a_index,
+                                        col_a_index,
+                                        row_b_index,
+                                        col_b_index
+                                    );
+                                    continue 'outer;
+                                }
+                            }
+                        }
+                    }
+
+                    trace!(
+                        Debug,
+                        "Confirmed area {} with points ({},{}) and ({},{})",
+                        area,
+                        row_a,
+                        col_a,
+                        row_b,
+                        col_b
+                    );
+                    max_area = area;
+                }
+            }
+            max_area
+        })
+        .max()
+        .expect("At least one area"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_core::generate::Rng;
+    use proptest::prelude::*;
+    use std::collections::HashSet;
+
+    const EXAMPLE: &str = "7,1
+11,1
+11,7
+9,7
+9,5
+2,5
+2,3
+7,3
+";
+
+    #[test]
+    fn test_day_09_part_1() {
+        assert_eq!(day_09_part_1(EXAMPLE), Ok(50));
+    }
+
+    #[test]
+    fn test_day_09_part_2() {
+        assert_eq!(day_09_part_2(EXAMPLE), Ok(24));
+    }
+
+    #[test]
+    fn test_day_09_visualize() {
+        let frames = day_09_visualize(EXAMPLE).unwrap();
+        assert_eq!(frames.len(), 2);
+        assert_eq!(
+            frames[1].to_ascii(),
+            "................
+...#####........
+...#XXX#........
+.###XXX#........
+.#XXXXX#........
+.#####X#........
+.....#X#........
+.....###........
+................
+................
+................
+................
+................
+................
+................
+................
+"
+        );
+    }
+
+    #[test]
+    fn test_day_09_part_2_harder1() {
+        let harder_example = "1,0
+3,0
+3,6
+16,6
+16,0
+18,0
+18,9
+13,9
+13,7
+6,7
+6,9
+1,9";
+        assert_eq!(day_09_part_2(harder_example), Ok(30));
+    }
+
+    #[test]
+    #[ignore = "coordinate compression misses the tiles between adjacent rows or columns"]
+    fn test_day_09_part_2_harder2() {
+        let harder_example = "1,1
+8,1
+8,3
+3,3
+3,4
+8,4
+8,9
+18,9
+18,11
+5,11
+5,9
+4,9
+4,11
+1,11
+1,7
+6,7
+6,6
+1,6";
+        assert_eq!(day_09_part_2(harder_example), Ok(88));
+    }
+
+    #[test]
+    #[ignore = "coordinate compression misses the tiles between adjacent rows or columns"]
+    fn test_day_09_part_2_harder3() {
+        let harder_example = "1,5
+3,5
+3,8
+7,8
+7,5
+9,5
+9,10
+11,10
+11,3
+6,3
+6,7
+4,7
+4,1
+13,1
+13,12
+1,12";
+        assert_eq!(day_09_part_2(harder_example), Ok(72));
+    }
+
+    #[test]
+    #[ignore = "coordinate compression misses the tiles between adjacent rows or columns"]
+    fn test_day_09_part_2_harder4() {
+        let harder_example = "0,0
+0,1
+1,1
+1,2
+0,2
+0,3
+3,3
+3,2
+2,2
+2,1
+3,1
+3,0";
+        assert_eq!(day_09_part_2(harder_example), Ok(16));
+    }
+
+    #[test]
+    fn test_day_09_generate() {
+        let data = day_09_generate(&mut Rng::new(9), 40);
+        assert_eq!(data.lines().count(), 40);
+        let part_2 = day_09_part_2(&data).unwrap();
+        assert!(part_2 > 0);
+        assert!(part_2 <= day_09_part_1(&data).unwrap());
+    }
+
+    #[test]
+    fn test_day_09_invalid_input() {
+        assert!(day_09_part_1("7,1\n11").is_err());
+        assert!(day_09_part_2("7,1\n2147483648,1").is_err());
+        assert_eq!(day_09_part_1("0,0\n2147483647,2147483647"), Ok(1 << 62));
+        assert_eq!(day_09_part_2("7,1"), Ok(0));
+    }
+
+    // Reference implementation, checking every tile of every rectangle.
+    fn naive_day_09(data: &str) -> (i64, i64) {
+        let points = parse_input_data(data).expect("Failed to parse input data");
+        let edges: Vec<_> = points
+            .iter()
+            .zip(points.iter().cycle().skip(1))
+            .map(|(&a, &b)| (a, b))
+            .collect();
+        let is_red_or_green = |row: u64, col: u64| {
+            let on_edge = edges.iter().any(|&((row_a, col_a), (row_b, col_b))| {
+                (row_a.min(row_b)..=row_a.max(row_b)).contains(&row)
+                    && (col_a.min(col_b)..=col_a.max(col_b)).contains(&col)
+            });
+            // cast a ray to the right, the half-open rows handle the corners
+            let nb_crossings = edges
+                .iter()
+                .filter(|&&((row_a, col_a), (row_b, col_b))| {
+                    col_a == col_b
+                        && col_a > col
+                        && (row_a.min(row_b)..row_a.max(row_b)).contains(&row)
+                })
+                .count();
+            on_edge || nb_crossings % 2 == 1
+        };
+
+        let (mut largest_area, mut largest_red_and_green_area) = (0, 0);
+        for (index, &(row_a, col_a)) in points.iter().enumerate() {
+            for &(row_b, col_b) in &points[index + 1..] {
+                let rows = row_a.min(row_b)..=row_a.max(row_b);
+                let cols = col_a.min(col_b)..=col_a.max(col_b);
+                let area =
+                    ((rows.end() - rows.start() + 1) * (cols.end() - cols.start() + 1)) as i64;
+                largest_area = largest_area.max(area);
+                if area > largest_red_and_green_area
+                    && rows
+                        .clone()
+                        .all(|row| cols.clone().all(|col| is_red_or_green(row, col)))
+                {
+                    largest_red_and_green_area = area;
+                }
+            }
+        }
+        (largest_area, largest_red_and_green_area)
+    }
+
+    // A polyomino whose outline is a simple loop: no holes, and no cells touching by a corner only.
+    fn is_simple_polyomino(cells: &Array2<bool>) -> bool {
+        let (nb_rows, nb_cols) = cells.dim();
+        for row in 0..nb_rows - 1 {
+            for col in 0..nb_cols - 1 {
+                let window = cells.slice(s![row..row + 2, col..col + 2]);
+                if window[(0, 0)] == window[(1, 1)]
+                    && window[(0, 1)] == window[(1, 0)]
+                    && window[(0, 0)] != window[(0, 1)]
+                {
+                    return false;
+                }
+            }
+        }
+        // every empty cell is reachable from the outside
+        let mut outside = Array2::from_elem((nb_rows + 2, nb_cols + 2), false);
+        let mut stack = vec![(0, 0)];
+        while let Some((row, col)) = stack.pop() {
+            let is_cell = row >= 1
+                && row <= nb_rows
+                && col >= 1
+                && col <= nb_cols
+                && cells[(row - 1, col - 1)];
+            if outside[(row, col)] || is_cell {
+                continue;
+            }
+            outside[(row, col)] = true;
+            if row > 0 {
+                stack.push((row - 1, col));
+            }
+            if row + 1 < nb_rows + 2 {
+                stack.push((row + 1, col));
+            }
+            if col > 0 {
+                stack.push((row, col - 1));
+            }
+            if col + 1 < nb_cols + 2 {
+                stack.push((row, col + 1));
+            }
+        }
+        let nb_empty = cells.iter().filter(|cell| !**cell).count();
+        let nb_outside = outside.iter().filter(|cell| **cell).count();
+        nb_outside == nb_empty + 2 * (nb_rows + nb_cols) + 4
+    }
+
+    // Grow a polyomino from the centre, skipping the growths that would make it not simple.
+    fn grow_polyomino(size: usize, growths: &[(usize, usize)]) -> Array2<bool> {
+        let mut cells = Array2::from_elem((size, size), false);
+        let mut filled = vec![(size / 2, size / 2)];
+        cells[filled[0]] = true;
+        for &(cell, direction) in growths {
+            let (row, col) = filled[cell % filled.len()];
+            let (row, col) = match direction {
+                0 => (row.wrapping_sub(1), col),
+                1 => (row + 1, col),
+                2 => (row, col.wrapping_sub(1)),
+                _ => (row, col + 1),
+            };
+            if row >= size || col >= size || cells[(row, col)] {
+                continue;
+            }
+            cells[(row, col)] = true;
+            if is_simple_polyomino(&cells) {
+                filled.push((row, col));
+            } else {
+                cells[(row, col)] = false;
+            }
+        }
+        cells
+    }
+
+    // The corners of the polyomino outline, in order.
+    fn outline_corners(cells: &Array2<bool>) -> Vec<(usize, usize)> {
+        let mut sides = HashSet::new();
+        for ((row, col), _) in cells.indexed_iter().filter(|(_, cell)| **cell) {
+            let corners = [
+                (row, col),
+                (row, col + 1),
+                (row + 1, col + 1),
+                (row + 1, col),
+            ];
+            for side in 0..4 {
+                let (from, to) = (corners[side], corners[(side + 1) % 4]);
+                // a side shared by two cells is inside the polyomino
+                if !sides.remove(&(to, from)) {
+                    sides.insert((from, to));
+                }
+            }
+        }
+        let edges: HashMap<_, _> = sides.into_iter().collect();
+        let start = *edges.keys().min().unwrap();
+        let mut outline = vec![start];
+        let mut current = edges[&start];
+        while current != start {
+            outline.push(current);
+            current = edges[&current];
+        }
+        let nb_points = outline.len();
+        (0..nb_points)
+            .filter(|&index| {
+                let (prev, next) = (
+                    outline[(index + nb_points - 1) % nb_points],
+                    outline[(index + 1) % nb_points],
+                );
+                prev.0 != next.0 && prev.1 != next.1
+            })
+            .map(|index| outline[index])
+            .collect()
+    }
+
+    // Rectilinear loops, without two distinct rows or columns of red tiles next to each other,
+    // as the coordinates compression doesn't handle those (see the ignored harder tests above).
+    fn floors() -> impl Strategy<Value = String> {
+        (2..7_usize)
+            .prop_flat_map(|size| {
+                (
+                    Just(size),
+                    prop::collection::vec((0..64_usize, 0..4_usize), 0..24),
+                    prop::collection::vec(2..5_u64, size + 1),
+                    prop::collection::vec(2..5_u64, size + 1),
+                )
+            })
+            .prop_map(|(size, growths, row_gaps, col_gaps)| {
+                let cells = grow_polyomino(size, &growths);
+                let coordinates = |gaps: Vec<u64>| {
+                    gaps.iter()
+                        .scan(0, |coordinate, gap| {
+                            *coordinate += gap;
+                            Some(*coordinate)
+                        })
+                        .collect::<Vec<_>>()
+                };
+                let (rows, cols) = (coordinates(row_gaps), coordinates(col_gaps));
+                render(
+                    outline_corners(&cells)
+                        .iter()
+                        .map(|&(row, col)| format!("{},{}", cols[col], rows[row])),
+                    "\n",
+                )
+            })
+    }
+
+    proptest! {
+        #[test]
+        fn test_day_09_against_naive(data in floors()) {
+            let (part_1, part_2) = naive_day_09(&data);
+            prop_assert_eq!(day_09_part_1(&data), Ok(part_1));
+            prop_assert_eq!(day_09_part_2(&data), Ok(part_2));
+        }
+    }
+}
@@ -0,0 +1,533 @@
+/*
+    Day 10 part 1 looks like a breadth-first search problem, with some dynamic programming or memoization.
+    I think there is a hack / trick to do it very quickly, and I feel like we have seen that kind of problem before,
+    in earlier years, but I will implement the straightforward solution first. It might be faster for part 1.
+
+    Part 2 looks harder.
+
+    ---
+    One quick optimisation for part 1 is to convert the Vec<bool> to a BitVec.
+
+    It runs a bit below 1ms on my machine.
+
+    I implemented the XOR operation after getting the hint from the subreddit.
+    Then it can run in about 0.75ms.
+    ---
+    As expected, part 2 is harder and I think I have to implement a proper solution
+    this time. I'm guessing on graphs, and I will check the solution instead of
+    trying to guess it.
+
+    So, it turns out that part 2 is really hard today. It's either
+    bruteforcing for hours, or using an MILP solver such as Z3 or similar.
+    Potentials:
+    - https://docs.rs/z3/latest/z3/
+    - https://github.com/specy/microlp
+
+    The use of microlp was pretty neat. It runs between 5 and 6ms
+    on my machine. I had to round the results though, as it had
+    small floating point errors like 42.999998 instead of 43, that
+    was then converted to 42 when casting to i64.
+    Also had to use integer variables because you can't half-press a button.
+
+    The model is now written with the ilp module, that does the rounding and
+    checks the rounded solution against the constraints. The buttons are also
+    bounded by their joltage requirements instead of an arbitrary 1000.
+
+    The machines are checked after parsing: at most 16 lights so they fit in a u16,
+    buttons wired to existing lights, and one joltage requirement per light.
+
+*/
+
+use std::{cmp::Reverse, collections::BinaryHeap};
+
+use bitvec::{field::BitField, order::Lsb0, vec::BitVec, view::BitView};
+use nom::{
+    IResult, Parser,
+    branch::alt,
+    character::complete::{char, space1},
+    combinator::value,
+    multi::{many1, separated_list1},
+};
+
+use aoc_core::error::InputError;
+use aoc_core::generate::Rng;
+use aoc_core::ilp::{Comparison, Direction, IlpError, LinearExpr, Model};
+use aoc_core::parallel::prelude::*;
+use aoc_core::parsers::{bracketed, bracketed_list, lines, parse_input};
+use aoc_core::trace::trace;
+
+fn parse_light_diagram(data: &str) -> IResult<&str, BitVec> {
+    bracketed(
+        '[',
+        many1(alt((value(false, char('.')), value(true, char('#')))))
+            .map(|v| v.into_iter().collect()),
+        ']',
+    )
+    .parse(data)
+}
+
+fn parse_button_wiring_schematic(data: &str) -> IResult<&str, Vec<usize>> {
+    bracketed_list('(', nom::character::complete::usize, ')').parse(data)
+}
+
+fn parse_joltage_requirements(data: &str) -> IResult<&str, Vec<u64>> {
+    bracketed_list('{', nom::character::complete::u64, '}').parse(data)
+}
+
+#[derive(Debug)]
+struct Machine {
+    light_diagram: BitVec,
+    button_wiring_schematics: Vec<Vec<usize>>,
+    joltage_requirements: Vec<u64>,
+}
+
+fn parse_machine(data: &str) -> IResult<&str, Machine> {
+    (
+        parse_light_diagram,
+        space1,
+        separated_list1(space1, parse_button_wiring_schematic),
+        space1,
+        parse_joltage_requirements,
+    )
+        .map(
+            |(light_diagram, _, button_wiring_schematics, _, joltage_requirements)| Machine {
+                light_diagram,
+                button_wiring_schematics,
+                joltage_requirements,
+            },
+        )
+        .parse(data)
+}
+
+fn check_machine(index: usize, machine: &Machine) -> Result<(), InputError> {
+    let nb_lights = machine.light_diagram.len();
+    let invalid = |message: &str| {
+        Err(InputError::invalid(format!(
+            "Machine {}: {}",
+            index + 1,
+            message
+        )))
+    };
+    if nb_lights > 16 {
+        return invalid("more than 16 lights");
+    }
+    if machine
+        .button_wiring_schematics
+        .iter()
+        .flatten()
+        .any(|&light| light >= nb_lights)
+    {
+        return invalid("a button is wired to a light that doesn't exist");
+    }
+    if machine.joltage_requirements.len() != nb_lights {
+        return invalid("not one joltage requirement per light");
+    }
+    if machine
+        .joltage_requirements
+        .iter()
+        .any(|&joltage| joltage > i32::MAX as u64)
+    {
+        return invalid("joltage requirement too high");
+    }
+    Ok(())
+}
+
+fn parse_input_data(data: &str) -> Result<Vec<Machine>, InputError> {
+    let machines = parse_input(lines(parse_machine), data)?;
+    for (index, machine) in machines.iter().enumerate() {
+        check_machine(index, machine)?;
+    }
+    Ok(machines)
+}
+
+fn part_1_machine_computation(machine: &Machine) -> Option<usize> {
+    // serialise to u16
+    let u16_light_diagram = machine.light_diagram.load_be::<u16>();
+    trace!(
+        Trace,
+        "Light diagram {:?} as u16: {:016b}", machine.light_diagram, u16_light_diagram
+    );
+
+    let mut binary_heap: BinaryHeap<Reverse<(usize, u16)>> = BinaryHeap::new();
+    binary_heap.push(Reverse((0, u16_light_diagram)));
+
+    //let mut visited: BitVec = BitVec::repeat(false, 1 << machine.light_diagram.len());
+    //let mut visited: HashSet<u16> = HashSet::new();
+    let mut visited: Vec<bool> = vec![false; 1 << machine.light_diagram.len()];
+
+    let wirings_as_u16: Vec<u16> = machine
+        .button_wiring_schematics
+        .iter()
+        .map(|wiring| {
+            let mut wiring_u16: u16 = 0;
+            let bitvec_view = wiring_u16.view_bits_mut::<Lsb0>();
+            for &button_index in wiring {
+                bitvec_view.set(button_index, true);
+            }
+
+            wiring_u16
+        })
+        .collect();
+
+    while let Some(Reverse((steps, state))) = binary_heap.pop() {
+        if state == 0 {
+            trace!(Debug, "{} presses for {:?}", steps, machine.light_diagram);
+            return Some(steps);
+        }
+
+        //if *visited.get(state as usize).unwrap() {
+        //if visited.contains(&state) {
+        if visited[state as usize] {
+            continue;
+        }
+        //visited.set(state as usize, true);
+        //visited.insert(state);
+        visited[state as usize] = true;
+
+        /*for wiring in &machine.button_wiring_schematics {
+            let mut new_state = state;
+            let new_state_bitvec = new_state.view_bits_mut::<Lsb0>();
+
+            for &button_index in wiring {
+                new_state_bitvec.set(button_index, !new_state_bitvec[button_index]);
+            }
+            binary_heap.push(Reverse((steps + 1, new_state)));
+        }*/
+        for wiring_u16 in &wirings_as_u16 {
+            let new_state = state ^ wiring_u16;
+            if !visited[new_state as usize] {
+                binary_heap.push(Reverse((steps + 1, new_state)));
+            }
+        }
+    }
+
+    // the buttons can't turn off these lights
+    None
+}
+
+pub fn day_10_part_1(data: &str) -> Result<i64, InputError> {
+    let machines = parse_input_data(data)?;
+
+    Ok(machines
+        .par_iter()
+        .map(part_1_machine_computation)
+        .collect::<Option<Vec<usize>>>()
+        .ok_or_else(|| InputError::invalid("A machine's lights can't be configured"))?
+        .iter()
+        .sum::<usize>() as i64)
+}
+
+pub fn day_10_part_2(data: &str) -> Result<i64, InputError> {
+    /* use microlp::{Problem, OptimizationDirection, ComparisonOp};
+
+    // Maximize an objective function x + 2 * y of two continuous variables x >= 0 and 0 <= y <= 3
+    let mut problem = Problem::new(OptimizationDirection::Maximize);
+    let x = problem.add_var(1.0, (0.0, f64::INFINITY));
+    let y = problem.add_var(2.0, (0.0, 3.0));
+
+    // subject to constraints: x + y <= 4 and 2 * x + y >= 2.
+    problem.add_constraint(&[(x, 1.0), (y, 1.0)], ComparisonOp::Le, 4.0);
+    problem.add_constraint(&[(x, 2.0), (y, 1.0)], ComparisonOp::Ge, 2.0);
+
+    // Optimal value is 7, achieved at x = 1 and y = 3.
+    let solution = problem.solve().unwrap();
+    assert_eq!(solution.objective(), 7.0);
+    assert_eq!(solution[x], 1.0);
+    assert_eq!(solution[y], 3.0); */
+
+    //v0 = 3 = b4 + b5
+    //v1 = 5 = b1 + b5
+    //v2 = 4 = b2 + b3 + b4
+    //v3 = 7 = b0 + b1 + b3
+    //min(b0 + b1 + b2 + b3 + b4 + b5)
+
+    /*use microlp::{ComparisonOp, OptimizationDirection, Problem};
+    let mut problem = Problem::new(OptimizationDirection::Minimize);
+    let b0 = problem.add_var(1.0, (0.0, f64::INFINITY));
+    let b1 = problem.add_var(1.0, (0.0, f64::INFINITY));
+    let b2 = problem.add_var(1.0, (0.0, f64::INFINITY));
+    let b3 = problem.add_var(1.0, (0.0, f64::INFINITY));
+    let b4 = problem.add_var(1.0, (0.0, f64::INFINITY));
+    let b5 = problem.add_var(1.0, (0.0, f64::INFINITY));
+
+    problem.add_constraint(&[(b4, 1.0), (b5, 1.0)], ComparisonOp::Eq, 3.0);
+    problem.add_constraint(&[(b1, 1.0), (b5, 1.0)], ComparisonOp::Eq, 5.0);
+    problem.add_constraint(&[(b2, 1.0), (b3, 1.0), (b4, 1.0)], ComparisonOp::Eq, 4.0);
+    problem.add_constraint(&[(b0, 1.0), (b1, 1.0), (b3, 1.0)], ComparisonOp::Eq, 7.0);
+
+    let solution = problem.solve().expect("Should be solvable");
+    let result = solution.objective() as i64;
+    result*/
+
+    let machines = parse_input_data(data)?;
+    Ok(machines
+        .par_iter()
+        .map(part_2_lp_solution)
+        .collect::<Result<Vec<i64>, IlpError>>()
+        .map_err(|error| {
+            InputError::invalid(format!(
+                "A machine's joltages can't be configured: {}",
+                error
+            ))
+        })?
+        .iter()
+        .sum::<i64>())
+}
+
+fn part_2_lp_solution(machine: &Machine) -> Result<i64, IlpError> {
+    let mut model = Model::new(Direction::Minimise);
+
+    let variables = machine
+        .button_wiring_schematics
+        .iter()
+        .map(|wiring| {
+            // a button can't be pressed more often than its lowest joltage requirement
+            let max_presses = wiring
+                .iter()
+                .map(|&index| machine.joltage_requirements[index])
+                .min()
+                .unwrap_or(0);
+            let max_presses = i32::try_from(max_presses).map_err(|_| IlpError::Overflow)?;
+            Ok(model.integer_var((0, max_presses)))
+        })
+        .collect::<Result<Vec<_>, IlpError>>()?;
+    model.set_objective(variables.iter().copied().sum::<LinearExpr>());
+
+    for (joltage_requirement_index, &joltage_requirement) in
+        machine.joltage_requirements.iter().enumerate()
+    {
+        let constraint: LinearExpr = machine
+            .button_wiring_schematics
+            .iter()
+            .zip(variables.iter())
+            .filter(|(wiring, _)| wiring.contains(&joltage_requirement_index))
+            .map(|(_, &variable)| variable)
+            .sum();
+        model.add_constraint(constraint, Comparison::Eq, joltage_requirement as i64);
+    }
+    let solution = model.solve()?;
+    let nb_presses = solution.integer_objective()?;
+    trace!(
+        Debug,
+        "{} presses for {:?}: {:?}",
+        nb_presses,
+        machine.joltage_requirements,
+        variables
+            .iter()
+            .map(|&variable| solution.integer(variable))
+            .collect::<Vec<_>>()
+    );
+    Ok(nb_presses)
+}
+
+// Machines built from random button presses, so they can always be configured.
+pub fn day_10_generate(rng: &mut Rng, size: usize) -> String {
+    (0..size.max(1))
+        .map(|_| {
+            let nb_lights = rng.between(4, 10) as usize;
+            let nb_buttons = rng.between(3, nb_lights as u64 + 3) as usize;
+            let buttons: Vec<Vec<usize>> = (0..nb_buttons)
+                .map(|_| {
+                    let mut lights: Vec<usize> = (0..nb_lights).collect();
+                    rng.shuffle(&mut lights);
+                    lights.truncate(rng.between(1, nb_lights as u64 - 1) as usize);
+                    lights.sort_unstable();
+                    lights
+                })
+                .collect();
+
+            let mut light_diagram = vec![false; nb_lights];
+            let mut joltage_requirements = vec![0; nb_lights];
+            for button in &buttons {
+                let nb_presses = rng.between(0, 20);
+                for &light in button {
+                    light_diagram[light] ^= nb_presses % 2 == 1;
+                    joltage_requirements[light] += nb_presses;
+                }
+            }
+
+            let list =
+                |values: &mut dyn Iterator<Item = String>| values.collect::<Vec<_>>().join(",");
+            format!(
+                "[{}] {} {{{}}}",
+                light_diagram
+                    .iter()
+                    .map(|&on| if on { '#' } else { '.' })
+                    .collect::<String>(),
+                buttons
+                    .iter()
+                    .map(|button| format!(
+                        "({})",
+                        list(&mut button.iter().map(|light| light.to_string()))
+                    ))
+                    .collect::<Vec<_>>()
+                    .join(" "),
+                list(
+                    &mut joltage_requirements
+                        .iter()
+                        .map(|joltage| joltage.to_string())
+                )
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_core::generate::{Rng, render};
+    use proptest::prelude::*;
+
+    const EXAMPLE: &str = "[.##.] (3) (1,3) (2) (2,3) (0,2) (0,1) {3,5,4,7}
+[...#.] (0,2,3,4) (2,3) (0,4) (0,1,2) (1,2,3,4) {7,5,12,7,2}
+[.###.#] (0,1,2,3,4) (0,3,4) (0,1,2,4,5) (1,2) {10,11,11,5,10,5}";
+
+    #[test]
+    fn test_day_10_part_1() {
+        assert_eq!(day_10_part_1(EXAMPLE), Ok(7));
+    }
+
+    #[test]
+    fn test_day_10_part_2() {
+        assert_eq!(day_10_part_2(EXAMPLE), Ok(33));
+    }
+
+    #[test]
+    fn test_day_10_generate() {
+        let data = day_10_generate(&mut Rng::new(10), 20);
+        assert_eq!(data.lines().count(), 20);
+        assert!(day_10_part_1(&data).unwrap() <= day_10_part_2(&data).unwrap());
+    }
+
+    #[test]
+    fn test_day_10_invalid_input() {
+        assert!(day_10_part_1("[.##.] (3) (1,4) {3,5,4,7}").is_err());
+        assert!(day_10_part_2("[.##.] (3) (1,3) {3,5,4}").is_err());
+        assert!(day_10_part_1("[.................] (3) {1}").is_err());
+        // no button for the first two lights
+        assert!(day_10_part_1("[##..] (2) (3) {1,1,0,0}").is_err());
+        assert!(day_10_part_2("[##..] (2) (3) {1,1,0,0}").is_err());
+    }
+
+    #[test]
+    fn test_day_10_part_2_too_many_presses() {
+        // the parser rejects such joltages, the model must not truncate them either
+        let machine = Machine {
+            light_diagram: BitVec::new(),
+            button_wiring_schematics: vec![vec![0]],
+            joltage_requirements: vec![u64::MAX],
+        };
+        assert_eq!(part_2_lp_solution(&machine), Err(IlpError::Overflow));
+    }
+
+    // Fewest presses over every subset of the buttons, each pressed at most once.
+    fn naive_part_1(diagram: &[bool], buttons: &[Vec<usize>]) -> usize {
+        (0..1_usize << buttons.len())
+            .filter(|subset| {
+                let mut lights = vec![false; diagram.len()];
+                for (index, button) in buttons.iter().enumerate() {
+                    if subset & (1 << index) != 0 {
+                        for &light in button {
+                            lights[light] = !lights[light];
+                        }
+                    }
+                }
+                lights == diagram
+            })
+            .map(|subset| subset.count_ones() as usize)
+            .min()
+            .unwrap()
+    }
+
+    // Fewest presses over every number of presses of every button, a button
+    // being pressed at most as many times as the lowest joltage it increases.
+    fn naive_part_2(joltages: &[u64], buttons: &[Vec<usize>]) -> u64 {
+        let mut best = u64::MAX;
+        let mut presses = vec![0; buttons.len()];
+        loop {
+            let mut reached = vec![0; joltages.len()];
+            for (button, &nb_presses) in buttons.iter().zip(&presses) {
+                for &light in button {
+                    reached[light] += nb_presses;
+                }
+            }
+            if reached == joltages {
+                best = best.min(presses.iter().sum());
+            }
+            // next combination, like an odometer
+            let Some(index) = (0..buttons.len()).find(|&index| {
+                presses[index]
+                    < buttons[index]
+                        .iter()
+                        .map(|&light| joltages[light])
+                        .min()
+                        .unwrap()
+            }) else {
+                return best;
+            };
+            presses[index] += 1;
+            presses[..index].fill(0);
+        }
+    }
+
+    // A small machine, its diagram and joltages coming from pressing the buttons
+    // like the generated machines, so it can always be configured.
+    fn machine() -> impl Strategy<Value = (Vec<bool>, Vec<Vec<usize>>, Vec<u64>)> {
+        (1..5_usize)
+            .prop_flat_map(|nb_lights| {
+                (
+                    Just(nb_lights),
+                    prop::collection::vec((1..1_usize << nb_lights, 0..4_u64), 1..5),
+                )
+            })
+            .prop_map(|(nb_lights, buttons)| {
+                let mut diagram = vec![false; nb_lights];
+                let mut joltages = vec![0; nb_lights];
+                let buttons = buttons
+                    .into_iter()
+                    .map(|(wiring, nb_presses)| {
+                        let button: Vec<usize> = (0..nb_lights)
+                            .filter(|light| wiring & (1 << light) != 0)
+                            .collect();
+                        for &light in &button {
+                            diagram[light] ^= nb_presses % 2 == 1;
+                            joltages[light] += nb_presses;
+                        }
+                        button
+                    })
+                    .collect();
+                (diagram, buttons, joltages)
+            })
+    }
+
+    proptest! {
+        #[test]
+        fn test_day_10_against_naive(machines in prop::collection::vec(machine(), 1..4)) {
+            let data = render(
+                machines.iter().map(|(diagram, buttons, joltages)| {
+                    format!(
+                        "[{}] {} {{{}}}",
+                        render(diagram.iter().map(|&on| if on { '#' } else { '.' }), ""),
+                        render(
+                            buttons.iter().map(|button| format!("({})", render(button, ","))),
+                            " "
+                        ),
+                        render(joltages, ",")
+                    )
+                }),
+                "\n",
+            );
+            let part_1: usize = machines
+                .iter()
+                .map(|(diagram, buttons, _)| naive_part_1(diagram, buttons))
+                .sum();
+            let part_2: u64 = machines
+                .iter()
+                .map(|(_, buttons, joltages)| naive_part_2(joltages, buttons))
+                .sum();
+            prop_assert_eq!(day_10_part_1(&data), Ok(part_1 as i64));
+            prop_assert_eq!(day_10_part_2(&data), Ok(part_2 as i64));
+        }
+    }
+}
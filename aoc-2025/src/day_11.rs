@@ -0,0 +1,377 @@
+/*
+    Part 1 is about exploring a directed graph.
+    I use petgraph and tried to make it fast.
+
+    Part 2 was a bit harder to me because I didn't implement memoization correctly at first.
+    I had to ask AI agents (yes I know) to help me do it correctly. A bit weird, because
+    I have done memoization correctly in the past, I guess I was rusty.
+
+    I thought about checking intermediate paths and multiplying them though.
+
+    Later, the base-26 node indices were replaced by the shared graph module,
+    that interns arbitrary names and counts paths in topological order.
+
+    Missing devices and loops between devices are reported as invalid inputs,
+    only the loops reachable from where the paths start though.
+    The paths are counted in u64 first, and in u128 or big integers when that overflows.
+*/
+
+use nom::{
+    IResult, Parser,
+    bytes::complete::tag,
+    character::complete::{alphanumeric1, space1},
+    multi::separated_list1,
+    sequence::separated_pair,
+};
+
+use aoc_core::answer::Answer;
+use aoc_core::arithmetic::{Arithmetic, Count, checked_product, checked_sum, with_arithmetic};
+use aoc_core::error::InputError;
+use aoc_core::generate::Rng;
+use aoc_core::graph::{NamedGraph, PathsError};
+use aoc_core::parsers::{lines, parse_input};
+
+fn parse_line(input: &str) -> IResult<&str, (&str, Vec<&str>)> {
+    separated_pair(
+        alphanumeric1,
+        tag(": "),
+        separated_list1(space1, alphanumeric1),
+    )
+    .parse(input)
+}
+
+fn parse_input_data(input: &str) -> Result<Vec<(&str, Vec<&str>)>, InputError> {
+    parse_input(lines(parse_line), input)
+}
+
+fn too_many_paths() -> InputError {
+    InputError::invalid("Too many paths to count with this arithmetic")
+}
+
+fn paths_error(graph: &NamedGraph, error: PathsError) -> InputError {
+    match error {
+        PathsError::Overflow => too_many_paths(),
+        PathsError::Cycle(node) => {
+            InputError::invalid(format!("The devices loop through {}", graph.name(node)))
+        }
+    }
+}
+
+// The graph of the devices, checked to have the given devices in it.
+fn build_graph<'a>(
+    data: Vec<(&'a str, Vec<&'a str>)>,
+    devices: &[&str],
+) -> Result<NamedGraph<'a>, InputError> {
+    let graph = NamedGraph::from_adjacency_list(data);
+    if let Some(device) = devices
+        .iter()
+        .find(|&&device| graph.index(device).is_none())
+    {
+        return Err(InputError::invalid(format!("No device named {}", device)));
+    }
+    Ok(graph)
+}
+
+// Like widening!, but a loop is reported right away, whatever the arithmetic.
+fn widening_paths(
+    graph: &NamedGraph,
+    solve: impl Fn(Arithmetic) -> Result<Answer, PathsError>,
+) -> Result<Answer, InputError> {
+    for arithmetic in Arithmetic::ALL {
+        match solve(arithmetic) {
+            Err(PathsError::Overflow) => continue,
+            result => return result.map_err(|error| paths_error(graph, error)),
+        }
+    }
+    unreachable!("Big integers don't overflow")
+}
+
+fn nb_possible_paths<C: Count>(
+    graph: &NamedGraph,
+    start: &str,
+    end: &str,
+) -> Result<C, PathsError> {
+    let index = |name| graph.index(name).expect("Checked when building the graph");
+    graph.count_paths(index(start), index(end))
+}
+
+const PART_1_DEVICES: [&str; 2] = ["you", "out"];
+
+pub fn day_11_part_1(data: &str) -> Result<Answer, InputError> {
+    let graph = build_graph(parse_input_data(data)?, &PART_1_DEVICES)?;
+    widening_paths(&graph, |arithmetic| {
+        with_arithmetic!(arithmetic, nb_possible_paths(&graph, "you", "out"))
+    })
+}
+
+// Part 1 with a given arithmetic, failing when the paths don't fit.
+pub fn day_11_part_1_with(data: &str, arithmetic: Arithmetic) -> Result<Answer, InputError> {
+    let graph = build_graph(parse_input_data(data)?, &PART_1_DEVICES)?;
+    with_arithmetic!(arithmetic, nb_possible_paths(&graph, "you", "out"))
+        .map_err(|error| paths_error(&graph, error))
+}
+
+const PART_2_DEVICES: [&str; 4] = ["svr", "fft", "dac", "out"];
+
+fn nb_paths_through_fft_and_dac<C: Count>(graph: &NamedGraph) -> Result<C, PathsError> {
+    let paths = |start, end| nb_possible_paths::<C>(graph, start, end);
+    let fft_first = checked_product([
+        paths("svr", "fft")?,
+        paths("fft", "dac")?,
+        paths("dac", "out")?,
+    ])?;
+    let dac_first = checked_product([
+        paths("svr", "dac")?,
+        paths("dac", "fft")?,
+        paths("fft", "out")?,
+    ])?;
+    Ok(checked_sum([fft_first, dac_first])?)
+}
+
+pub fn day_11_part_2(data: &str) -> Result<Answer, InputError> {
+    let graph = build_graph(parse_input_data(data)?, &PART_2_DEVICES)?;
+    widening_paths(&graph, |arithmetic| {
+        with_arithmetic!(arithmetic, nb_paths_through_fft_and_dac(&graph))
+    })
+}
+
+// Part 2 with a given arithmetic, failing when the paths don't fit.
+pub fn day_11_part_2_with(data: &str, arithmetic: Arithmetic) -> Result<Answer, InputError> {
+    let graph = build_graph(parse_input_data(data)?, &PART_2_DEVICES)?;
+    with_arithmetic!(arithmetic, nb_paths_through_fft_and_dac(&graph))
+        .map_err(|error| paths_error(&graph, error))
+}
+
+// A random DAG, every device being wired to one or two devices further down.
+// The number of paths stays small this way, it grows like the square root of the size.
+pub fn day_11_generate(rng: &mut Rng, size: usize) -> String {
+    const SPECIAL_NAMES: [&str; 5] = ["svr", "you", "fft", "dac", "out"];
+    let nb_devices = size.max(SPECIAL_NAMES.len());
+    // base 26 names of at least 3 letters
+    let name = |mut index: usize| {
+        let mut name = Vec::new();
+        while index > 0 || name.len() < 3 {
+            name.push(b'a' + (index % 26) as u8);
+            index /= 26;
+        }
+        String::from_utf8(name).expect("Only ASCII letters")
+    };
+    let mut names: Vec<String> = (0..)
+        .map(name)
+        .filter(|name| !SPECIAL_NAMES.contains(&name.as_str()))
+        .take(nb_devices - SPECIAL_NAMES.len())
+        .collect();
+    rng.shuffle(&mut names);
+    // svr first, out last, and the others in between, in the order of the list
+    names.insert(0, "svr".to_string());
+    names.insert(
+        1 + rng.below(nb_devices / 10 + 1).min(names.len() - 1),
+        "you".to_string(),
+    );
+    names.insert(nb_devices / 3, "fft".to_string());
+    names.insert(2 * nb_devices / 3, "dac".to_string());
+    names.push("out".to_string());
+
+    let mut lines: Vec<String> = (0..nb_devices - 1)
+        .map(|index| {
+            let nb_outputs = if rng.chance(0.5) { 1 } else { 2 };
+            let mut outputs: Vec<&str> = (0..nb_outputs)
+                .map(|_| {
+                    names[rng.between(index as u64 + 1, nb_devices as u64 - 1) as usize].as_str()
+                })
+                .collect();
+            outputs.dedup();
+            format!("{}: {}", names[index], outputs.join(" "))
+        })
+        .collect();
+    rng.shuffle(&mut lines);
+    lines.join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_core::generate::{Rng, render};
+    use num_bigint::BigUint;
+    use proptest::prelude::*;
+
+    #[test]
+    fn test_day_11_part_1() {
+        assert_eq!(
+            day_11_part_1(
+                "aaa: you hhh
+you: bbb ccc
+bbb: ddd eee
+ccc: ddd eee fff
+ddd: ggg
+eee: out
+fff: out
+ggg: out
+hhh: ccc fff iii
+iii: out"
+            ),
+            Ok(Answer::Integer(5))
+        );
+    }
+
+    #[test]
+    fn test_day_11_part_2() {
+        assert_eq!(
+            day_11_part_2(
+                "svr: aaa bbb
+aaa: fft
+fft: ccc
+bbb: tty
+tty: ccc
+ccc: ddd eee
+ddd: hub
+hub: fff
+eee: dac
+dac: fff
+fff: ggg hhh
+ggg: out
+hhh: out"
+            ),
+            Ok(Answer::Integer(2))
+        );
+    }
+
+    #[test]
+    fn test_day_11_generate() {
+        let data = day_11_generate(&mut Rng::new(11), 200);
+        assert_eq!(data.lines().count(), 199);
+        for name in ["svr", "you", "fft", "dac"] {
+            assert!(data.contains(&format!("{}: ", name)));
+        }
+        assert!(day_11_part_1(&data).unwrap().as_i64().unwrap() >= 0);
+        assert!(day_11_part_2(&data).unwrap().as_i64().unwrap() >= 0);
+    }
+
+    #[test]
+    fn test_day_11_invalid_input() {
+        assert!(day_11_part_1("aaa: bbb\nbbb: out").is_err());
+        assert!(day_11_part_1("you: bbb\nbbb: you out").is_err());
+        assert!(day_11_part_2("you: out").is_err());
+        assert!(day_11_part_1("you: bbb\nbbb:").is_err());
+        assert_eq!(
+            day_11_part_2("svr: fft\nfft: dac\ndac: out\naaa: bbb\nbbb: aaa"),
+            Ok(Answer::Integer(1))
+        );
+        assert!(day_11_part_2("svr: fft\nfft: dac aaa\ndac: out\naaa: fft").is_err());
+    }
+
+    #[test]
+    fn test_day_11_unreachable_loop() {
+        // the loop can't be reached from you
+        let data = "aaa: bbb\nbbb: aaa out\nyou: ccc out\nccc: out";
+        assert_eq!(day_11_part_1(data), Ok(Answer::Integer(2)));
+        assert_eq!(
+            day_11_part_1_with(data, Arithmetic::Checked),
+            Ok(Answer::Integer(2))
+        );
+    }
+
+    #[test]
+    fn test_day_11_too_many_paths() {
+        // svr to fft, fft to dac and dac to out have 2^45 paths each
+        let mut lines = Vec::new();
+        for (from, to) in [("svr", "fft"), ("fft", "dac"), ("dac", "out")] {
+            let node = |index| match index {
+                0 => from.to_string(),
+                90 => to.to_string(),
+                _ => format!("{}{}", from, index),
+            };
+            for layer in (0..90).step_by(2) {
+                lines.push(format!(
+                    "{}: {} {}",
+                    node(layer),
+                    node(layer + 1),
+                    node(layer + 2)
+                ));
+                lines.push(format!("{}: {}", node(layer + 1), node(layer + 2)));
+            }
+        }
+        lines.push("you: out".to_string());
+        let data = lines.join("\n");
+
+        assert_eq!(day_11_part_1(&data), Ok(Answer::Integer(1)));
+        assert!(day_11_part_2_with(&data, Arithmetic::Checked).is_err());
+        assert!(day_11_part_2_with(&data, Arithmetic::Wide).is_err());
+        assert_eq!(
+            day_11_part_2(&data),
+            Ok(Answer::from(BigUint::from(2_u8).pow(135)))
+        );
+    }
+
+    // Reference implementation, following every path one at a time and
+    // ticking off the devices it has to go through.
+    fn naive_paths(edges: &[(&str, &str)], node: &str, end: &str, through: &[&str]) -> i64 {
+        let through: Vec<&str> = through
+            .iter()
+            .copied()
+            .filter(|&device| device != node)
+            .collect();
+        if node == end {
+            return through.is_empty() as i64;
+        }
+        edges
+            .iter()
+            .filter(|&&(from, _)| from == node)
+            .map(|&(_, to)| naive_paths(edges, to, end, &through))
+            .sum()
+    }
+
+    // The devices in a random order, each wired to some of the devices after it,
+    // so they never loop. A device without any wire doesn't appear in the input.
+    fn devices() -> impl Strategy<Value = Vec<(&'static str, &'static str)>> {
+        let names = vec!["svr", "you", "fft", "dac", "aaa", "bbb", "ccc", "out"];
+        let nb_pairs = names.len() * (names.len() - 1) / 2;
+        (
+            Just(names).prop_shuffle(),
+            prop::collection::vec(prop::bool::weighted(0.4), nb_pairs),
+        )
+            .prop_map(|(names, wired)| {
+                let pairs = (0..names.len())
+                    .flat_map(|from| (from + 1..names.len()).map(move |to| (from, to)));
+                pairs
+                    .zip(wired)
+                    .filter(|&(_, wired)| wired)
+                    .map(|((from, to), _)| (names[from], names[to]))
+                    .collect()
+            })
+    }
+
+    proptest! {
+        #[test]
+        fn test_day_11_against_naive(edges in devices()) {
+            let mut sources: Vec<&str> = edges.iter().map(|&(from, _)| from).collect();
+            sources.dedup();
+            let data = render(
+                sources.iter().map(|&source| {
+                    let targets = edges.iter().filter(|&&(from, _)| from == source);
+                    format!("{}: {}", source, render(targets.map(|&(_, to)| to), " "))
+                }),
+                "\n",
+            );
+            let present =
+                |device: &str| edges.iter().any(|&(from, to)| from == device || to == device);
+
+            if ["you", "out"].into_iter().all(present) {
+                prop_assert_eq!(
+                    day_11_part_1(&data),
+                    Ok(Answer::Integer(naive_paths(&edges, "you", "out", &[])))
+                );
+            } else {
+                prop_assert!(day_11_part_1(&data).is_err());
+            }
+            if ["svr", "fft", "dac", "out"].into_iter().all(present) {
+                prop_assert_eq!(
+                    day_11_part_2(&data),
+                    Ok(Answer::Integer(naive_paths(&edges, "svr", "out", &["fft", "dac"])))
+                );
+            } else {
+                prop_assert!(day_11_part_2(&data).is_err());
+            }
+        }
+    }
+}
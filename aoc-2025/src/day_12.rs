@@ -0,0 +1,262 @@
+/**
+ * I felt like the problem was way too hard to be solved in a day.
+ *
+ * So I waited. Eventually I got spoiled by Reddit and found that
+ * the actual input data had some very nice property that made the problem
+ * trivial to solve.
+ *
+ * I also saw a few versions of the algorithm that was very easy to implement.
+ * So I implemented a good enough parser and did the quick and dirty solution.
+ *
+ * I don't think I will attempt a proper optimal bin-packing solution for this one
+ * before a while, if ever.
+ *
+ * It was the last one, and perhaps the most unsatisfying one.
+ *
+ * But a good short advent of code season overall!
+ */
+use nom::{
+    IResult, Parser,
+    character::complete::{char, space1},
+    multi::separated_list1,
+    sequence::separated_pair,
+};
+
+use aoc_core::error::InputError;
+use aoc_core::generate::Rng;
+use aoc_core::parallel::prelude::*;
+use aoc_core::parsers::{lines, parse_input, sections};
+
+fn parse_size(input: &str) -> IResult<&str, (usize, usize)> {
+    separated_pair(
+        nom::character::complete::usize,
+        char('x'),
+        nom::character::complete::usize,
+    )
+    .parse(input)
+}
+
+type InputLine = ((usize, usize), Vec<usize>);
+fn parse_line(input: &str) -> IResult<&str, InputLine> {
+    separated_pair(
+        parse_size,
+        (char(':'), space1),
+        separated_list1(space1, nom::character::complete::usize),
+    )
+    .parse(input)
+}
+
+fn parse_input_data(input: &str) -> Result<Vec<InputLine>, InputError> {
+    parse_input(lines(parse_line), input)
+}
+
+pub fn day_12_part_1(data: &str) -> Result<i64, InputError> {
+    // the shapes come first, and are not needed for the quick and dirty solution
+    let regions = sections(data)
+        .pop()
+        .ok_or_else(|| InputError::invalid("No regions in input data"))?;
+    let data = parse_input_data(regions)?;
+
+    let fits = data
+        .par_iter()
+        .map(|((w, h), n)| {
+            let needed = n
+                .iter()
+                .try_fold(0_usize, |sum, &count| sum.checked_add(count))?;
+            let available = (w / 3).checked_mul(h / 3)?;
+            Some(needed <= available)
+        })
+        .collect::<Option<Vec<bool>>>()
+        .ok_or_else(|| InputError::invalid("A region is too large"))?;
+    Ok(fits.iter().filter(|&&fits| fits).count() as i64)
+}
+
+// Six shapes and regions that are more or less big enough for their presents.
+pub fn day_12_generate(rng: &mut Rng, size: usize) -> String {
+    let mut sections: Vec<String> = (0..6)
+        .map(|index| {
+            let mut cells = [true, true, true, true, true, true, false, false, false];
+            rng.shuffle(&mut cells[1..]);
+            let rows = cells
+                .chunks(3)
+                .map(|row| {
+                    row.iter()
+                        .map(|&cell| if cell { '#' } else { '.' })
+                        .collect::<String>()
+                })
+                .collect::<Vec<_>>();
+            format!("{}:\n{}", index, rows.join("\n"))
+        })
+        .collect();
+    let regions = (0..size.max(1))
+        .map(|_| {
+            let (width, height) = (rng.between(4, 50), rng.between(4, 50));
+            let area = (width / 3) * (height / 3);
+            // up to two presents more or less than what fits, never below zero
+            let nb_presents = (area + rng.between(0, 4)).saturating_sub(2);
+            let mut counts = [0; 6];
+            for _ in 0..nb_presents {
+                counts[rng.below(6)] += 1;
+            }
+            let counts = counts.map(|count| count.to_string());
+            format!("{}x{}: {}", width, height, counts.join(" "))
+        })
+        .collect::<Vec<_>>();
+    sections.push(regions.join("\n"));
+    sections.join("\n\n")
+}
+
+pub fn day_12_part_2(_data: &str) -> Result<i64, InputError> {
+    Ok(42)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_core::generate::{Rng, render};
+    use proptest::prelude::*;
+    use std::collections::BTreeSet;
+
+    #[test]
+    fn test_day_12_generate() {
+        let data = day_12_generate(&mut Rng::new(12), 100);
+        assert_eq!(sections(&data).len(), 7);
+        let nb_regions = day_12_part_1(&data).unwrap();
+        assert!(nb_regions > 0 && nb_regions < 100);
+    }
+
+    #[test]
+    fn test_day_12_generate_large() {
+        // the smallest regions fit a single present, it used to underflow
+        let data = day_12_generate(&mut Rng::new(12), 20_000);
+        assert_eq!(sections(&data)[6].lines().count(), 20_000);
+        assert!(day_12_part_1(&data).is_ok());
+    }
+
+    #[test]
+    fn test_day_12_invalid_input() {
+        assert!(day_12_part_1("").is_err());
+        assert!(day_12_part_1("0:\n###\n\n12x5 1 0").is_err());
+        assert!(day_12_part_1("99999999999999999999x3: 1").is_err());
+        assert!(day_12_part_1("18446744073709551615x18446744073709551615: 1").is_err());
+        assert_eq!(day_12_part_1("6x3: 1 1"), Ok(1));
+    }
+
+    type Cells = Vec<(usize, usize)>;
+    type Transform = fn((usize, usize)) -> (usize, usize);
+
+    // The cells of a 3x3 shape, in its eight rotations and flips.
+    fn orientations(shape: &[&str]) -> Vec<Cells> {
+        let cells: Cells = shape
+            .iter()
+            .enumerate()
+            .flat_map(|(row, line)| {
+                line.chars()
+                    .enumerate()
+                    .filter(|&(_, cell)| cell == '#')
+                    .map(move |(col, _)| (row, col))
+            })
+            .collect();
+        let transforms: [Transform; 8] = [
+            |(r, c)| (r, c),
+            |(r, c)| (c, 2 - r),
+            |(r, c)| (2 - r, 2 - c),
+            |(r, c)| (2 - c, r),
+            |(r, c)| (r, 2 - c),
+            |(r, c)| (c, r),
+            |(r, c)| (2 - r, c),
+            |(r, c)| (2 - c, 2 - r),
+        ];
+        let orientations: BTreeSet<Cells> = transforms
+            .iter()
+            .map(|transform| {
+                let mut oriented: Cells = cells.iter().copied().map(transform).collect();
+                oriented.sort_unstable();
+                oriented
+            })
+            .collect();
+        orientations.into_iter().collect()
+    }
+
+    // Reference implementation, trying every placement of every present until they all fit.
+    // The presents of the same shape are placed in order, not to try them in every order.
+    fn naive_fits(
+        occupied: &mut [Vec<bool>],
+        shapes: &[Vec<Cells>],
+        presents: &[usize],
+        first_position: usize,
+    ) -> bool {
+        let Some((&shape, rest)) = presents.split_first() else {
+            return true;
+        };
+        let (height, width) = (occupied.len(), occupied[0].len());
+        for position in first_position..height * width {
+            let (row, col) = (position / width, position % width);
+            for cells in &shapes[shape] {
+                let placed: Cells = cells.iter().map(|&(r, c)| (row + r, col + c)).collect();
+                if placed
+                    .iter()
+                    .any(|&(r, c)| r >= height || c >= width || occupied[r][c])
+                {
+                    continue;
+                }
+                placed.iter().for_each(|&(r, c)| occupied[r][c] = true);
+                let next_position = if rest.first() == Some(&shape) {
+                    position
+                } else {
+                    0
+                };
+                let fits = naive_fits(occupied, shapes, rest, next_position);
+                placed.iter().for_each(|&(r, c)| occupied[r][c] = false);
+                if fits {
+                    return true;
+                }
+            }
+        }
+        false
+    }
+
+    // Tiny regions, with full 3x3 presents like the real input data behaves,
+    // that the quick and dirty solution is right about.
+    fn regions() -> impl Strategy<Value = Vec<((usize, usize), Vec<usize>)>> {
+        prop::collection::vec(
+            (
+                (1..9_usize, 1..9_usize),
+                prop::collection::vec(0..2_usize, 6),
+            ),
+            1..5,
+        )
+    }
+
+    proptest! {
+        #[test]
+        fn test_day_12_against_naive(regions in regions()) {
+            let shape = ["###", "###", "###"];
+            let shapes = vec![orientations(&shape); 6];
+            let data = format!(
+                "{}\n\n{}",
+                render((0..6).map(|index| format!("{}:\n{}", index, shape.join("\n"))), "\n\n"),
+                render(
+                    regions.iter().map(|((width, height), counts)| {
+                        format!("{}x{}: {}", width, height, render(counts, " "))
+                    }),
+                    "\n",
+                )
+            );
+            let nb_fitting = regions
+                .iter()
+                .filter(|((width, height), counts)| {
+                    let presents: Vec<usize> = counts
+                        .iter()
+                        .enumerate()
+                        .flat_map(|(shape, &count)| std::iter::repeat_n(shape, count))
+                        .collect();
+                    // not even enough room for all the cells
+                    presents.iter().map(|&shape| shapes[shape][0].len()).sum::<usize>() <= width * height
+                        && naive_fits(&mut vec![vec![false; *width]; *height], &shapes, &presents, 0)
+                })
+                .count();
+            prop_assert_eq!(day_12_part_1(&data), Ok(nb_fitting as i64));
+        }
+    }
+}
@@ -0,0 +1,109 @@
+/*
+    The days of 2025, as a library so the fuzz targets can use them,
+    and their registry with the embedded inputs for the runner of aoc-core.
+    The aoc-wasm crate exposes the same registry to JavaScript.
+
+    Every day is behind its own cargo feature, all enabled by default, so a
+    subset can be built with for example --no-default-features --features day_01.
+*/
+
+use aoc_core::registry::Day;
+
+#[cfg(feature = "day_01")]
+pub mod day_01;
+#[cfg(feature = "day_02")]
+pub mod day_02;
+#[cfg(feature = "day_03")]
+pub mod day_03;
+#[cfg(feature = "day_04")]
+pub mod day_04;
+#[cfg(feature = "day_05")]
+pub mod day_05;
+#[cfg(feature = "day_06")]
+pub mod day_06;
+#[cfg(feature = "day_07")]
+pub mod day_07;
+#[cfg(feature = "day_08")]
+pub mod day_08;
+#[cfg(feature = "day_09")]
+pub mod day_09;
+#[cfg(feature = "day_10")]
+pub mod day_10;
+#[cfg(feature = "day_11")]
+pub mod day_11;
+#[cfg(feature = "day_12")]
+pub mod day_12;
+
+// Without the inputs feature, the days have empty inputs and only solve
+// the ones they're given, the web page doesn't need to embed them.
+#[cfg(feature = "inputs")]
+macro_rules! input {
+    ($day:literal) => {
+        include_str!(concat!("../inputs/day_", $day, ".txt"))
+    };
+}
+
+#[cfg(not(feature = "inputs"))]
+macro_rules! input {
+    ($day:literal) => {
+        ""
+    };
+}
+
+// Only the days enabled by their feature.
+macro_rules! days {
+    // the optional functions of a day, listed after it
+    (@visualize $day:literal) => {
+        None
+    };
+    (@visualize $day:literal visualize $($extra:ident)*) => {
+        paste::paste! { Some([<day_ $day>]::[<day_ $day _visualize>]) }
+    };
+    (@visualize $day:literal $other:ident $($extra:ident)*) => {
+        days!(@visualize $day $($extra)*)
+    };
+    (@explain $day:literal) => {
+        None
+    };
+    (@explain $day:literal explain $($extra:ident)*) => {
+        paste::paste! { Some([<day_ $day>]::[<day_ $day _explain>]) }
+    };
+    (@explain $day:literal $other:ident $($extra:ident)*) => {
+        days!(@explain $day $($extra)*)
+    };
+    ($($feature:literal => $day:literal $(+ $extra:ident)*),*) => {
+        &[
+            $(
+                #[cfg(feature = $feature)]
+                paste::paste! {
+                    Day {
+                        year: 2025,
+                        name: $day,
+                        input: input!($day),
+                        // the days answer with integers or anything else
+                        part_1: |data| [<day_ $day>]::[<day_ $day _part_1>](data).map(aoc_core::answer::Answer::from),
+                        part_2: |data| [<day_ $day>]::[<day_ $day _part_2>](data).map(aoc_core::answer::Answer::from),
+                        generate: [<day_ $day>]::[<day_ $day _generate>],
+                        visualize: days!(@visualize $day $($extra)*),
+                        explain: days!(@explain $day $($extra)*),
+                    }
+                }
+            ),*
+        ]
+    };
+}
+
+pub const DAYS: &[Day] = days!(
+    "day_01" => "01" + explain,
+    "day_02" => "02" + explain,
+    "day_03" => "03",
+    "day_04" => "04",
+    "day_05" => "05",
+    "day_06" => "06",
+    "day_07" => "07" + visualize,
+    "day_08" => "08" + visualize,
+    "day_09" => "09" + visualize,
+    "day_10" => "10",
+    "day_11" => "11",
+    "day_12" => "12"
+);
@@ -0,0 +1,5 @@
+use std::process::ExitCode;
+
+fn main() -> ExitCode {
+    aoc_core::runner::main(&[aoc_2025::DAYS])
+}
@@ -0,0 +1,153 @@
+/*
+    What a part returns. Most answers fit in an i64, but some counts grow
+    exponentially with the input, and some puzzles answer with text,
+    or with letters drawn on a screen.
+
+    The integers are normalised: an answer is only a big integer when it
+    doesn't fit in an i64, so 40_u64 and 40_i64 give the same answer.
+*/
+
+use std::fmt::{self, Display};
+
+use num_bigint::{BigInt, BigUint};
+
+use crate::ocr::ocr;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Answer {
+    Integer(i64),
+    BigInteger(BigInt),
+    Text(String),
+    // several lines of # and ., usually block letters
+    Art(String),
+}
+
+impl Answer {
+    pub fn art(art: impl Into<String>) -> Self {
+        Answer::Art(art.into())
+    }
+
+    pub fn as_i64(&self) -> Option<i64> {
+        match self {
+            Answer::Integer(value) => Some(*value),
+            _ => None,
+        }
+    }
+
+    // The answer as it would be typed on the website, on a single line.
+    // The art is read with the OCR, and kept as is when it can't be read.
+    pub fn text(&self) -> String {
+        match self {
+            Answer::Art(art) => ocr(art).unwrap_or_else(|| art.clone()),
+            answer => answer.to_string(),
+        }
+    }
+
+    pub fn kind(&self) -> &'static str {
+        match self {
+            Answer::Integer(_) => "integer",
+            Answer::BigInteger(_) => "big_integer",
+            Answer::Text(_) => "text",
+            Answer::Art(_) => "art",
+        }
+    }
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Answer::Integer(value) => write!(f, "{}", value),
+            Answer::BigInteger(value) => write!(f, "{}", value),
+            Answer::Text(text) => write!(f, "{}", text),
+            Answer::Art(art) => write!(f, "{}", art),
+        }
+    }
+}
+
+impl From<i64> for Answer {
+    fn from(value: i64) -> Self {
+        Answer::Integer(value)
+    }
+}
+
+impl From<BigInt> for Answer {
+    fn from(value: BigInt) -> Self {
+        match i64::try_from(&value) {
+            Ok(value) => Answer::Integer(value),
+            Err(_) => Answer::BigInteger(value),
+        }
+    }
+}
+
+impl From<BigUint> for Answer {
+    fn from(value: BigUint) -> Self {
+        BigInt::from(value).into()
+    }
+}
+
+impl From<u64> for Answer {
+    fn from(value: u64) -> Self {
+        BigInt::from(value).into()
+    }
+}
+
+impl From<u128> for Answer {
+    fn from(value: u128) -> Self {
+        BigInt::from(value).into()
+    }
+}
+
+impl From<String> for Answer {
+    fn from(text: String) -> Self {
+        Answer::Text(text)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(text: &str) -> Self {
+        Answer::Text(text.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_normalised_integers() {
+        assert_eq!(Answer::from(40_u64), Answer::Integer(40));
+        assert_eq!(Answer::from(40_u128), Answer::from(BigUint::from(40_u32)));
+        assert_eq!(
+            Answer::from(u64::MAX),
+            Answer::BigInteger(BigInt::from(u64::MAX))
+        );
+        assert_eq!(Answer::from(i64::MAX as u64).as_i64(), Some(i64::MAX));
+        assert_eq!(Answer::from(u128::MAX).as_i64(), None);
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(Answer::from(-3_i64).to_string(), "-3");
+        assert_eq!(
+            Answer::from(u128::MAX).to_string(),
+            "340282366920938463463374607431768211455"
+        );
+        assert_eq!(Answer::from("EZFZ").to_string(), "EZFZ");
+    }
+
+    #[test]
+    fn test_art() {
+        let answer = Answer::art(
+            "####.#..#
+#....#..#
+###..####
+#....#..#
+#....#..#
+####.#..#",
+        );
+        assert_eq!(answer.text(), "EH");
+        assert_eq!(answer.kind(), "art");
+        assert_eq!(answer.to_string().lines().count(), 6);
+        assert_eq!(Answer::art("#.#").text(), "#.#");
+    }
+}
@@ -0,0 +1,142 @@
+/*
+    Counting without silently wrapping around.
+
+    The solvers that count things growing exponentially are written once,
+    generic over a Count, and run with one of three arithmetics:
+    - Checked: u64, the overflows are detected and reported,
+    - Wide: u128, the same but further away,
+    - Big: arbitrary precision integers, slower but never overflowing.
+
+    widening!(solve(…)) tries them in that order, so the usual inputs stay fast
+    and the big integers are only used when the narrower types overflow.
+*/
+
+use std::fmt::Debug;
+
+// for the macros, the days don't have to depend on num-bigint
+#[doc(hidden)]
+pub use num_bigint::BigUint;
+use num_traits::{CheckedAdd, CheckedMul, One, Zero};
+
+use crate::answer::Answer;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Arithmetic {
+    Checked,
+    Wide,
+    Big,
+}
+
+impl Arithmetic {
+    pub const ALL: [Arithmetic; 3] = [Arithmetic::Checked, Arithmetic::Wide, Arithmetic::Big];
+}
+
+// The count didn't fit in the integer type of the arithmetic.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Overflow;
+
+pub trait Count:
+    Clone + Debug + Zero + One + CheckedAdd + CheckedMul + From<u64> + Into<Answer>
+{
+}
+
+impl<T> Count for T where
+    T: Clone + Debug + Zero + One + CheckedAdd + CheckedMul + From<u64> + Into<Answer>
+{
+}
+
+pub fn checked_sum<C: Count>(values: impl IntoIterator<Item = C>) -> Result<C, Overflow> {
+    values
+        .into_iter()
+        .try_fold(C::zero(), |sum, value| sum.checked_add(&value))
+        .ok_or(Overflow)
+}
+
+pub fn checked_product<C: Count>(values: impl IntoIterator<Item = C>) -> Result<C, Overflow> {
+    values
+        .into_iter()
+        .try_fold(C::one(), |product, value| product.checked_mul(&value))
+        .ok_or(Overflow)
+}
+
+// Run a solver generic over Count with the given arithmetic,
+// as a Result<Answer, Overflow>.
+#[macro_export]
+macro_rules! with_arithmetic {
+    ($arithmetic:expr, $solve:ident($($arg:expr),* $(,)?)) => {
+        match $arithmetic {
+            $crate::arithmetic::Arithmetic::Checked => {
+                $solve::<u64>($($arg),*).map($crate::answer::Answer::from)
+            }
+            $crate::arithmetic::Arithmetic::Wide => {
+                $solve::<u128>($($arg),*).map($crate::answer::Answer::from)
+            }
+            $crate::arithmetic::Arithmetic::Big => {
+                $solve::<$crate::arithmetic::BigUint>($($arg),*).map($crate::answer::Answer::from)
+            }
+        }
+    };
+}
+
+// Run a solver generic over Count with the narrowest arithmetic that doesn't overflow.
+#[macro_export]
+macro_rules! widening {
+    ($solve:ident($($arg:expr),* $(,)?)) => {
+        $crate::arithmetic::Arithmetic::ALL
+            .iter()
+            .find_map(|&arithmetic| $crate::arithmetic::with_arithmetic!(arithmetic, $solve($($arg),*)).ok())
+            .expect("Big integers don't overflow")
+    };
+}
+
+pub use crate::{widening, with_arithmetic};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn power_of_two<C: Count>(exponent: u32) -> Result<C, Overflow> {
+        checked_product((0..exponent).map(|_| C::from(2)))
+    }
+
+    #[test]
+    fn test_checked_sum_and_product() {
+        assert_eq!(checked_sum([1_u64, 2, 3]), Ok(6));
+        assert_eq!(checked_sum([u64::MAX, 1]), Err(Overflow));
+        assert_eq!(checked_product(Vec::<u64>::new()), Ok(1));
+        assert_eq!(checked_product([u64::MAX, 2]), Err(Overflow));
+        assert_eq!(
+            checked_product([u64::MAX as u128, 2]),
+            Ok(2 * u64::MAX as u128)
+        );
+    }
+
+    #[test]
+    fn test_with_arithmetic() {
+        assert_eq!(
+            with_arithmetic!(Arithmetic::Checked, power_of_two(10)),
+            Ok(Answer::Integer(1024))
+        );
+        assert_eq!(
+            with_arithmetic!(Arithmetic::Checked, power_of_two(64)),
+            Err(Overflow)
+        );
+        assert_eq!(
+            with_arithmetic!(Arithmetic::Wide, power_of_two(64)),
+            Ok(Answer::from(1_u128 << 64))
+        );
+        assert_eq!(
+            with_arithmetic!(Arithmetic::Wide, power_of_two(128)),
+            Err(Overflow)
+        );
+    }
+
+    #[test]
+    fn test_widening() {
+        assert_eq!(widening!(power_of_two(62)), Answer::Integer(1 << 62));
+        assert_eq!(
+            widening!(power_of_two(200)).to_string(),
+            "1606938044258990275541962092341162602522202993782792835301376"
+        );
+    }
+}
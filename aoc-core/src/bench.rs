@@ -0,0 +1,98 @@
+/*
+    Runtime versus input size, on the synthetic inputs of the generate module.
+
+    The results are printed as CSV, one line per day and size, so they can be
+    charted with anything. The time kept is the best of a few runs.
+*/
+
+use std::time::{Duration, Instant};
+
+use crate::generate::{Generator, Rng};
+use crate::registry::Part;
+
+pub const DEFAULT_SIZES: [usize; 7] = [16, 32, 64, 128, 256, 512, 1024];
+
+pub struct Measurement {
+    pub size: usize,
+    pub input_len: usize,
+    pub part_1: Duration,
+    pub part_2: Duration,
+}
+
+fn best_time(part: Part, data: &str, nb_runs: usize) -> Duration {
+    (0..nb_runs.max(1))
+        .map(|_| {
+            let now = Instant::now();
+            let _ = std::hint::black_box(part(std::hint::black_box(data)));
+            now.elapsed()
+        })
+        .min()
+        .expect("At least one run")
+}
+
+pub fn measure(
+    generate: Generator,
+    part_1: Part,
+    part_2: Part,
+    sizes: &[usize],
+    seed: u64,
+    nb_runs: usize,
+) -> Vec<Measurement> {
+    sizes
+        .iter()
+        .map(|&size| {
+            let data = generate(&mut Rng::new(seed), size);
+            Measurement {
+                size,
+                input_len: data.len(),
+                part_1: best_time(part_1, &data, nb_runs),
+                part_2: best_time(part_2, &data, nb_runs),
+            }
+        })
+        .collect()
+}
+
+pub const CSV_HEADER: &str = "day,size,input_bytes,part_1_us,part_2_us";
+
+pub fn csv_lines(day: &str, measurements: &[Measurement]) -> Vec<String> {
+    measurements
+        .iter()
+        .map(|measurement| {
+            format!(
+                "{},{},{},{},{}",
+                day,
+                measurement.size,
+                measurement.input_len,
+                measurement.part_1.as_micros(),
+                measurement.part_2.as_micros()
+            )
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::answer::Answer;
+    use crate::error::InputError;
+
+    fn lines(_rng: &mut Rng, size: usize) -> String {
+        vec!["1"; size].join("\n")
+    }
+
+    fn count_lines(data: &str) -> Result<Answer, InputError> {
+        Ok(Answer::from(data.lines().count() as i64))
+    }
+
+    #[test]
+    fn test_measure() {
+        let measurements = measure(lines, count_lines, count_lines, &[1, 10], 0, 2);
+        assert_eq!(measurements.len(), 2);
+        assert_eq!(measurements[1].size, 10);
+        assert_eq!(measurements[1].input_len, 19);
+
+        let csv = csv_lines("01", &measurements);
+        assert!(csv[0].starts_with("01,1,1,"));
+        assert_eq!(csv[1].split(',').count(), CSV_HEADER.split(',').count());
+    }
+}
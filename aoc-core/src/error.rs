@@ -0,0 +1,89 @@
+/*
+    What can be wrong with an input: it doesn't parse, or it parses but doesn't make
+    sense for the puzzle, like a bank too short or a cycle between the devices.
+
+    The days return these errors instead of panicking, so they can be fed anything,
+    by the fuzz targets for example.
+*/
+
+use std::fmt::{self, Display};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InputError {
+    // lines and columns start at 1, like in an editor
+    Parse {
+        line: usize,
+        column: usize,
+        near: String,
+    },
+    Invalid(String),
+}
+
+impl InputError {
+    // rest is the part of data that couldn't be parsed,
+    // the whitespace before it doesn't count
+    pub fn parse(data: &str, rest: &str) -> Self {
+        let rest = rest.trim_start();
+        let offset = data.len().saturating_sub(rest.len());
+        let parsed = &data[..data.floor_char_boundary(offset)];
+        let line = parsed.matches('\n').count() + 1;
+        let column = parsed.chars().rev().take_while(|&c| c != '\n').count() + 1;
+        let near = rest.lines().next().unwrap_or("").chars().take(20).collect();
+        InputError::Parse { line, column, near }
+    }
+
+    pub fn invalid(message: impl Into<String>) -> Self {
+        InputError::Invalid(message.into())
+    }
+}
+
+impl Display for InputError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            InputError::Parse { line, column, near } => write!(
+                f,
+                "Failed to parse input data at line {}, column {}, near {:?}",
+                line, column, near
+            ),
+            InputError::Invalid(message) => write!(f, "Invalid input data: {}", message),
+        }
+    }
+}
+
+impl std::error::Error for InputError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_position() {
+        let data = "L68\nL30\nX48\n";
+        let error = InputError::parse(data, &data[7..]);
+        assert_eq!(
+            error,
+            InputError::Parse {
+                line: 3,
+                column: 1,
+                near: "X48".to_string()
+            }
+        );
+        assert_eq!(
+            error.to_string(),
+            "Failed to parse input data at line 3, column 1, near \"X48\""
+        );
+    }
+
+    #[test]
+    fn test_parse_at_the_end() {
+        let error = InputError::parse("12-", "");
+        assert_eq!(
+            error,
+            InputError::Parse {
+                line: 1,
+                column: 4,
+                near: String::new()
+            }
+        );
+    }
+}
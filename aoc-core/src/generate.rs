@@ -0,0 +1,115 @@
+/*
+    Synthetic inputs, to see how the days scale beyond the one real input.
+
+    Every day has a day_XX_generate(rng, size) function that writes a valid input,
+    size being roughly the number of lines (rotations, banks, points, machines…).
+    The same seed always gives the same input, so the benchmarks can be compared.
+
+    The random number generator is SplitMix64, it's tiny and good enough for this.
+    render() writes the items of an input, it's used by the property tests as well.
+*/
+
+use std::fmt::{Display, Write};
+
+pub type Generator = fn(&mut Rng, usize) -> String;
+
+pub struct Rng(u64);
+
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        Rng(seed)
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    // Uniform in low..=high, the modulo bias doesn't matter here.
+    pub fn between(&mut self, low: u64, high: u64) -> u64 {
+        assert!(low <= high, "Empty range {}..={}", low, high);
+        match (high - low).checked_add(1) {
+            Some(span) => low + self.next_u64() % span,
+            None => self.next_u64(),
+        }
+    }
+
+    pub fn below(&mut self, bound: usize) -> usize {
+        assert!(bound > 0, "Empty range");
+        (self.next_u64() % bound as u64) as usize
+    }
+
+    pub fn chance(&mut self, probability: f64) -> bool {
+        let uniform = (self.next_u64() >> 11) as f64 / (1_u64 << 53) as f64;
+        uniform < probability
+    }
+
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for index in (1..items.len()).rev() {
+            items.swap(index, self.below(index + 1));
+        }
+    }
+}
+
+// The items one after the other, such as one rotation per line
+// or ranges separated by commas.
+pub fn render<I>(items: I, separator: &str) -> String
+where
+    I: IntoIterator,
+    I::Item: Display,
+{
+    let mut output = String::new();
+    for (index, item) in items.into_iter().enumerate() {
+        if index > 0 {
+            output.push_str(separator);
+        }
+        write!(output, "{}", item).expect("Writing to a String doesn't fail");
+    }
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_same_seed_same_numbers() {
+        let mut a = Rng::new(42);
+        let mut b = Rng::new(42);
+        for _ in 0..100 {
+            assert_eq!(a.next_u64(), b.next_u64());
+        }
+        assert_ne!(Rng::new(1).next_u64(), Rng::new(2).next_u64());
+    }
+
+    #[test]
+    fn test_between() {
+        let mut rng = Rng::new(7);
+        for _ in 0..1000 {
+            let value = rng.between(3, 5);
+            assert!((3..=5).contains(&value));
+        }
+        assert_eq!(rng.between(9, 9), 9);
+        rng.between(0, u64::MAX);
+    }
+
+    #[test]
+    fn test_shuffle() {
+        let mut rng = Rng::new(7);
+        let mut items: Vec<usize> = (0..20).collect();
+        rng.shuffle(&mut items);
+        assert_ne!(items, (0..20).collect::<Vec<_>>());
+        items.sort_unstable();
+        assert_eq!(items, (0..20).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn test_render() {
+        assert_eq!(render([1, 2, 3], "\n"), "1\n2\n3");
+        assert_eq!(render(["11-22", "95-115"], ","), "11-22,95-115");
+        assert_eq!(render(Vec::<u8>::new(), ","), "");
+    }
+}
@@ -0,0 +1,331 @@
+/*
+    Directed graphs with named nodes, on top of petgraph.
+
+    The names are borrowed from the input and interned to node indices
+    the first time they are seen, as a source or as a target.
+
+    Counting the paths between two nodes only looks at the part of the graph
+    reachable from the start, so a cycle elsewhere doesn't prevent it.
+*/
+
+use std::collections::HashMap;
+use std::fmt::{self, Display};
+
+use petgraph::{
+    algo::{Cycle, has_path_connecting, is_cyclic_directed, toposort},
+    graph::{DiGraph, NodeIndex},
+    visit::Dfs,
+};
+
+use crate::arithmetic::{Count, Overflow, checked_sum};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PathsError {
+    Cycle(NodeIndex),
+    Overflow,
+}
+
+impl Display for PathsError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            PathsError::Cycle(node) => write!(f, "cycle through node {}", node.index()),
+            PathsError::Overflow => write!(f, "too many paths to count with this arithmetic"),
+        }
+    }
+}
+
+impl std::error::Error for PathsError {}
+
+impl From<Overflow> for PathsError {
+    fn from(_: Overflow) -> Self {
+        PathsError::Overflow
+    }
+}
+
+// The state of a node during the depth-first search counting the paths.
+#[derive(Debug, Clone)]
+enum Visit<C> {
+    New,
+    // its successors are being counted, it's on the current path
+    Open,
+    // None when it doesn't fit in the count type
+    Counted(Option<C>),
+}
+
+impl From<Cycle<NodeIndex>> for PathsError {
+    fn from(cycle: Cycle<NodeIndex>) -> Self {
+        PathsError::Cycle(cycle.node_id())
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct NamedGraph<'a> {
+    graph: DiGraph<&'a str, ()>,
+    indices: HashMap<&'a str, NodeIndex>,
+}
+
+impl<'a> NamedGraph<'a> {
+    pub fn new() -> Self {
+        NamedGraph {
+            graph: DiGraph::new(),
+            indices: HashMap::new(),
+        }
+    }
+
+    // Build the graph from lines such as "aaa: bbb ccc", already parsed.
+    pub fn from_adjacency_list<I, N>(adjacency_list: I) -> Self
+    where
+        I: IntoIterator<Item = (&'a str, N)>,
+        N: IntoIterator<Item = &'a str>,
+    {
+        let mut named_graph = Self::new();
+        for (from, neighbours) in adjacency_list {
+            let from = named_graph.intern(from);
+            for to in neighbours {
+                let to = named_graph.intern(to);
+                named_graph.graph.add_edge(from, to, ());
+            }
+        }
+        named_graph
+    }
+
+    pub fn intern(&mut self, name: &'a str) -> NodeIndex {
+        *self
+            .indices
+            .entry(name)
+            .or_insert_with(|| self.graph.add_node(name))
+    }
+
+    pub fn add_edge(&mut self, from: &'a str, to: &'a str) {
+        let from = self.intern(from);
+        let to = self.intern(to);
+        self.graph.add_edge(from, to, ());
+    }
+
+    pub fn index(&self, name: &str) -> Option<NodeIndex> {
+        self.indices.get(name).copied()
+    }
+
+    pub fn name(&self, index: NodeIndex) -> &'a str {
+        self.graph[index]
+    }
+
+    pub fn graph(&self) -> &DiGraph<&'a str, ()> {
+        &self.graph
+    }
+
+    pub fn node_count(&self) -> usize {
+        self.graph.node_count()
+    }
+
+    pub fn has_cycle(&self) -> bool {
+        is_cyclic_directed(&self.graph)
+    }
+
+    pub fn topological_order(&self) -> Result<Vec<NodeIndex>, Cycle<NodeIndex>> {
+        toposort(&self.graph, None)
+    }
+
+    pub fn is_reachable(&self, from: NodeIndex, to: NodeIndex) -> bool {
+        has_path_connecting(&self.graph, from, to, None)
+    }
+
+    // Every node reachable from start, start included, in depth-first order.
+    pub fn reachable_from(&self, start: NodeIndex) -> Vec<NodeIndex> {
+        let mut dfs = Dfs::new(&self.graph, start);
+        let mut reachable = Vec::new();
+        while let Some(node) = dfs.next(&self.graph) {
+            reachable.push(node);
+        }
+        reachable
+    }
+
+    // Number of paths from every node to end, indexed by node index.
+    // None when it doesn't fit in the count type. Only defined for directed acyclic graphs.
+    pub fn count_paths_to<C: Count>(
+        &self,
+        end: NodeIndex,
+    ) -> Result<Vec<Option<C>>, Cycle<NodeIndex>> {
+        let order = self.topological_order()?;
+        let mut nb_paths = vec![Some(C::zero()); self.graph.node_count()];
+        nb_paths[end.index()] = Some(C::one());
+        // successors come after their predecessors in the topological order
+        for &node in order.iter().rev() {
+            if node == end {
+                continue;
+            }
+            nb_paths[node.index()] = self
+                .graph
+                .neighbors(node)
+                .map(|neighbour| nb_paths[neighbour.index()].clone())
+                .collect::<Option<Vec<C>>>()
+                .and_then(|counts| checked_sum(counts).ok());
+        }
+        Ok(nb_paths)
+    }
+
+    // The paths stop at end, so only a cycle reachable from start without going
+    // through end is an error.
+    pub fn count_paths<C: Count>(&self, start: NodeIndex, end: NodeIndex) -> Result<C, PathsError> {
+        let mut visits = vec![Visit::New; self.graph.node_count()];
+        // (node, true once its successors have been counted)
+        let mut stack = vec![(start, false)];
+        while let Some((node, counted_successors)) = stack.pop() {
+            if counted_successors {
+                let nb_paths = if node == end {
+                    Some(C::one())
+                } else {
+                    self.graph
+                        .neighbors(node)
+                        .map(|neighbour| match &visits[neighbour.index()] {
+                            Visit::Counted(nb_paths) => nb_paths.clone(),
+                            _ => unreachable!("Successors are counted first"),
+                        })
+                        .collect::<Option<Vec<C>>>()
+                        .and_then(|counts| checked_sum(counts).ok())
+                };
+                visits[node.index()] = Visit::Counted(nb_paths);
+                continue;
+            }
+            if !matches!(visits[node.index()], Visit::New) {
+                continue;
+            }
+            visits[node.index()] = Visit::Open;
+            stack.push((node, true));
+            if node == end {
+                continue;
+            }
+            for neighbour in self.graph.neighbors(node) {
+                match visits[neighbour.index()] {
+                    Visit::New => stack.push((neighbour, false)),
+                    Visit::Open => return Err(PathsError::Cycle(neighbour)),
+                    Visit::Counted(_) => {}
+                }
+            }
+        }
+        match &visits[start.index()] {
+            Visit::Counted(nb_paths) => nb_paths.clone().ok_or(PathsError::Overflow),
+            _ => unreachable!("Start is counted last"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn example() -> NamedGraph<'static> {
+        NamedGraph::from_adjacency_list([
+            ("you", vec!["bbb", "ccc"]),
+            ("bbb", vec!["ddd", "eee"]),
+            ("ccc", vec!["ddd", "eee", "fff"]),
+            ("ddd", vec!["ggg"]),
+            ("eee", vec!["out"]),
+            ("fff", vec!["out"]),
+            ("ggg", vec!["out"]),
+        ])
+    }
+
+    #[test]
+    fn test_interning() {
+        let graph = example();
+        assert_eq!(graph.node_count(), 8);
+        let out = graph.index("out").expect("out is a node");
+        assert_eq!(graph.name(out), "out");
+        assert_eq!(graph.index("nope"), None);
+    }
+
+    #[test]
+    fn test_topological_order() {
+        let graph = example();
+        let order = graph.topological_order().expect("no cycle");
+        let position = |name| {
+            let index = graph.index(name).unwrap();
+            order.iter().position(|&node| node == index).unwrap()
+        };
+        assert!(position("you") < position("ccc"));
+        assert!(position("ddd") < position("ggg"));
+        assert!(position("ggg") < position("out"));
+    }
+
+    #[test]
+    fn test_reachability() {
+        let graph = example();
+        let ddd = graph.index("ddd").unwrap();
+        let mut reachable: Vec<&str> = graph
+            .reachable_from(ddd)
+            .into_iter()
+            .map(|node| graph.name(node))
+            .collect();
+        reachable.sort_unstable();
+        assert_eq!(reachable, vec!["ddd", "ggg", "out"]);
+        assert!(!graph.is_reachable(ddd, graph.index("fff").unwrap()));
+    }
+
+    #[test]
+    fn test_count_paths() {
+        let graph = example();
+        let you = graph.index("you").unwrap();
+        let out = graph.index("out").unwrap();
+        assert_eq!(graph.count_paths::<u64>(you, out), Ok(5));
+        assert_eq!(graph.count_paths::<u64>(out, you), Ok(0));
+    }
+
+    #[test]
+    fn test_cycle() {
+        let mut graph = example();
+        assert!(!graph.has_cycle());
+        graph.add_edge("out", "you");
+        assert!(graph.has_cycle());
+        assert!(graph.topological_order().is_err());
+        let you = graph.index("you").unwrap();
+        let out = graph.index("out").unwrap();
+        // the paths stop at out, before going back to you
+        assert_eq!(graph.count_paths::<u64>(you, out), Ok(5));
+        graph.add_edge("ggg", "ddd");
+        assert!(matches!(
+            graph.count_paths::<u64>(you, out),
+            Err(PathsError::Cycle(_))
+        ));
+    }
+
+    #[test]
+    fn test_unreachable_cycle() {
+        let mut graph = example();
+        graph.add_edge("aaa", "zzz");
+        graph.add_edge("zzz", "aaa");
+        graph.add_edge("zzz", "out");
+        assert!(graph.has_cycle());
+        let you = graph.index("you").unwrap();
+        let out = graph.index("out").unwrap();
+        assert_eq!(graph.count_paths::<u64>(you, out), Ok(5));
+        let zzz = graph.index("zzz").unwrap();
+        assert!(matches!(
+            graph.count_paths::<u64>(zzz, out),
+            Err(PathsError::Cycle(_))
+        ));
+    }
+
+    #[test]
+    fn test_count_paths_overflow() {
+        // every layer doubles the number of paths
+        let names: Vec<String> = (0..=130).map(|index| format!("n{}", index)).collect();
+        let mut graph = NamedGraph::new();
+        for layer in (0..130).step_by(2) {
+            graph.add_edge(&names[layer], &names[layer + 1]);
+            graph.add_edge(&names[layer], &names[layer + 2]);
+            graph.add_edge(&names[layer + 1], &names[layer + 2]);
+        }
+        let end = graph.index("n130").unwrap();
+        let start = graph.index("n0").unwrap();
+        assert_eq!(
+            graph.count_paths::<u64>(graph.index("n126").unwrap(), end),
+            Ok(4)
+        );
+        assert_eq!(
+            graph.count_paths::<u64>(start, end),
+            Err(PathsError::Overflow)
+        );
+        assert_eq!(graph.count_paths::<u128>(start, end), Ok(1 << 65));
+    }
+}
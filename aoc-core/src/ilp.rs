@@ -0,0 +1,476 @@
+/*
+    A small modelling layer on top of microlp.
+
+    Models are written with integer coefficients, such as `x + 2 * y <= 4`,
+    and the solutions are checked against the constraints before being returned.
+    microlp works with floats, so integer variables come back as 42.999998 from time
+    to time. They are rounded, and the rounded values are verified with exact arithmetic.
+
+    Scaling an expression by a coefficient too large for an i64 doesn't panic, the
+    expression remembers it overflowed and solving the model returns an error.
+*/
+
+use std::{
+    fmt::{self, Display},
+    iter::Sum,
+    ops::{Add, Mul},
+};
+
+use microlp::{ComparisonOp, OptimizationDirection, Problem};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Direction {
+    Minimise,
+    Maximise,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Comparison {
+    Eq,
+    Le,
+    Ge,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Var(usize);
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum VarKind {
+    Integer { lower: i32, upper: i32 },
+    Continuous { lower: f64, upper: f64 },
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct LinearExpr {
+    terms: Vec<(Var, i64)>,
+    // a coefficient didn't fit in an i64
+    overflow: bool,
+}
+
+impl LinearExpr {
+    pub fn new() -> Self {
+        LinearExpr {
+            terms: Vec::new(),
+            overflow: false,
+        }
+    }
+
+    pub fn add_term(&mut self, var: Var, coefficient: i64) {
+        self.terms.push((var, coefficient));
+    }
+
+    pub fn terms(&self) -> &[(Var, i64)] {
+        &self.terms
+    }
+
+    pub fn overflowed(&self) -> bool {
+        self.overflow
+    }
+}
+
+impl From<Var> for LinearExpr {
+    fn from(var: Var) -> Self {
+        LinearExpr {
+            terms: vec![(var, 1)],
+            overflow: false,
+        }
+    }
+}
+
+impl<E: Into<LinearExpr>> Add<E> for LinearExpr {
+    type Output = LinearExpr;
+
+    fn add(mut self, other: E) -> LinearExpr {
+        let other = other.into();
+        self.terms.extend(other.terms);
+        self.overflow = self.overflow || other.overflow;
+        self
+    }
+}
+
+impl<E: Into<LinearExpr>> Add<E> for Var {
+    type Output = LinearExpr;
+
+    fn add(self, other: E) -> LinearExpr {
+        LinearExpr::from(self) + other
+    }
+}
+
+impl Mul<Var> for i64 {
+    type Output = LinearExpr;
+
+    fn mul(self, var: Var) -> LinearExpr {
+        LinearExpr {
+            terms: vec![(var, self)],
+            overflow: false,
+        }
+    }
+}
+
+impl Mul<LinearExpr> for i64 {
+    type Output = LinearExpr;
+
+    fn mul(self, mut expr: LinearExpr) -> LinearExpr {
+        for (_, coefficient) in expr.terms.iter_mut() {
+            match coefficient.checked_mul(self) {
+                Some(product) => *coefficient = product,
+                None => expr.overflow = true,
+            }
+        }
+        expr
+    }
+}
+
+impl<E: Into<LinearExpr>> Sum<E> for LinearExpr {
+    fn sum<I: Iterator<Item = E>>(iter: I) -> Self {
+        iter.fold(LinearExpr::new(), |acc, expr| acc + expr)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum IlpError {
+    Infeasible,
+    Unbounded,
+    Solver(String),
+    // the solver answered, but its answer doesn't satisfy the model once rounded
+    ConstraintViolated(usize),
+    BoundViolated(Var),
+    // a coefficient or the objective value doesn't fit in an i64
+    Overflow,
+    // an exact integer value was asked for an expression with continuous variables
+    NotInteger,
+}
+
+impl Display for IlpError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            IlpError::Infeasible => write!(f, "the model is infeasible"),
+            IlpError::Unbounded => write!(f, "the model is unbounded"),
+            IlpError::Solver(message) => write!(f, "solver error: {}", message),
+            IlpError::ConstraintViolated(index) => {
+                write!(f, "the solution violates constraint {}", index)
+            }
+            IlpError::BoundViolated(var) => {
+                write!(f, "the solution violates the bounds of variable {}", var.0)
+            }
+            IlpError::Overflow => write!(f, "a value doesn't fit in an i64"),
+            IlpError::NotInteger => write!(f, "the expression has continuous variables"),
+        }
+    }
+}
+
+impl std::error::Error for IlpError {}
+
+impl From<microlp::Error> for IlpError {
+    fn from(error: microlp::Error) -> Self {
+        match error {
+            microlp::Error::Infeasible => IlpError::Infeasible,
+            microlp::Error::Unbounded => IlpError::Unbounded,
+            microlp::Error::InternalError(message) => IlpError::Solver(message),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Constraint {
+    expr: LinearExpr,
+    comparison: Comparison,
+    rhs: i64,
+}
+
+#[derive(Debug, Clone)]
+pub struct Model {
+    direction: Direction,
+    variables: Vec<VarKind>,
+    objective: LinearExpr,
+    constraints: Vec<Constraint>,
+}
+
+// tolerance for the continuous variables only
+const EPSILON: f64 = 1e-6;
+
+impl Model {
+    pub fn new(direction: Direction) -> Self {
+        Model {
+            direction,
+            variables: Vec::new(),
+            objective: LinearExpr::new(),
+            constraints: Vec::new(),
+        }
+    }
+
+    // microlp only supports i32 bounds for integer variables
+    pub fn integer_var(&mut self, (lower, upper): (i32, i32)) -> Var {
+        self.variables.push(VarKind::Integer { lower, upper });
+        Var(self.variables.len() - 1)
+    }
+
+    // Use f64::NEG_INFINITY and f64::INFINITY for missing bounds.
+    pub fn continuous_var(&mut self, (lower, upper): (f64, f64)) -> Var {
+        self.variables.push(VarKind::Continuous { lower, upper });
+        Var(self.variables.len() - 1)
+    }
+
+    pub fn set_objective(&mut self, objective: impl Into<LinearExpr>) {
+        self.objective = objective.into();
+    }
+
+    pub fn add_constraint(
+        &mut self,
+        expr: impl Into<LinearExpr>,
+        comparison: Comparison,
+        rhs: i64,
+    ) {
+        self.constraints.push(Constraint {
+            expr: expr.into(),
+            comparison,
+            rhs,
+        });
+    }
+
+    pub fn solve(&self) -> Result<Solution, IlpError> {
+        let mut problem = Problem::new(match self.direction {
+            Direction::Minimise => OptimizationDirection::Minimize,
+            Direction::Maximise => OptimizationDirection::Maximize,
+        });
+
+        if self.objective.overflowed()
+            || self
+                .constraints
+                .iter()
+                .any(|constraint| constraint.expr.overflowed())
+        {
+            return Err(IlpError::Overflow);
+        }
+
+        let mut objective_coefficients = vec![0_i64; self.variables.len()];
+        for &(var, coefficient) in self.objective.terms() {
+            objective_coefficients[var.0] = objective_coefficients[var.0]
+                .checked_add(coefficient)
+                .ok_or(IlpError::Overflow)?;
+        }
+
+        let microlp_variables = self
+            .variables
+            .iter()
+            .zip(objective_coefficients.iter())
+            .map(|(kind, &coefficient)| match *kind {
+                VarKind::Integer { lower, upper } => {
+                    problem.add_integer_var(coefficient as f64, (lower, upper))
+                }
+                VarKind::Continuous { lower, upper } => {
+                    problem.add_var(coefficient as f64, (lower, upper))
+                }
+            })
+            .collect::<Vec<_>>();
+
+        for constraint in self.constraints.iter() {
+            problem.add_constraint(
+                constraint
+                    .expr
+                    .terms()
+                    .iter()
+                    .map(|&(var, coefficient)| (microlp_variables[var.0], coefficient as f64))
+                    .collect::<Vec<_>>(),
+                match constraint.comparison {
+                    Comparison::Eq => ComparisonOp::Eq,
+                    Comparison::Le => ComparisonOp::Le,
+                    Comparison::Ge => ComparisonOp::Ge,
+                },
+                constraint.rhs as f64,
+            );
+        }
+
+        let solution = problem.solve()?;
+        let values = self
+            .variables
+            .iter()
+            .zip(microlp_variables.iter())
+            .map(|(kind, &microlp_var)| {
+                let value = *solution.var_value(microlp_var);
+                match kind {
+                    VarKind::Integer { .. } => Value::Integer(value.round() as i64),
+                    VarKind::Continuous { .. } => Value::Continuous(value),
+                }
+            })
+            .collect();
+
+        let solution = Solution {
+            values,
+            objective: self.objective.clone(),
+        };
+        self.verify(&solution)?;
+        Ok(solution)
+    }
+
+    fn verify(&self, solution: &Solution) -> Result<(), IlpError> {
+        for (index, kind) in self.variables.iter().enumerate() {
+            let within_bounds = match (*kind, solution.values[index]) {
+                (VarKind::Integer { lower, upper }, Value::Integer(value)) => {
+                    (lower as i64..=upper as i64).contains(&value)
+                }
+                (VarKind::Continuous { lower, upper }, Value::Continuous(value)) => {
+                    value >= lower - EPSILON && value <= upper + EPSILON
+                }
+                _ => unreachable!("Values are built from the variable kinds"),
+            };
+            if !within_bounds {
+                return Err(IlpError::BoundViolated(Var(index)));
+            }
+        }
+
+        for (index, constraint) in self.constraints.iter().enumerate() {
+            let satisfied = match solution.evaluate_exact(&constraint.expr) {
+                Some(lhs) => match constraint.comparison {
+                    Comparison::Eq => lhs == constraint.rhs as i128,
+                    Comparison::Le => lhs <= constraint.rhs as i128,
+                    Comparison::Ge => lhs >= constraint.rhs as i128,
+                },
+                None => {
+                    let lhs = solution.evaluate(&constraint.expr);
+                    let rhs = constraint.rhs as f64;
+                    match constraint.comparison {
+                        Comparison::Eq => (lhs - rhs).abs() <= EPSILON,
+                        Comparison::Le => lhs <= rhs + EPSILON,
+                        Comparison::Ge => lhs >= rhs - EPSILON,
+                    }
+                }
+            };
+            if !satisfied {
+                return Err(IlpError::ConstraintViolated(index));
+            }
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Value {
+    Integer(i64),
+    Continuous(f64),
+}
+
+#[derive(Debug, Clone)]
+pub struct Solution {
+    values: Vec<Value>,
+    objective: LinearExpr,
+}
+
+impl Solution {
+    pub fn value(&self, var: Var) -> f64 {
+        match self.values[var.0] {
+            Value::Integer(value) => value as f64,
+            Value::Continuous(value) => value,
+        }
+    }
+
+    pub fn integer(&self, var: Var) -> i64 {
+        match self.values[var.0] {
+            Value::Integer(value) => value,
+            Value::Continuous(_) => panic!("Variable {} is not an integer variable", var.0),
+        }
+    }
+
+    pub fn objective(&self) -> f64 {
+        self.evaluate(&self.objective)
+    }
+
+    // Exact objective value, when it only depends on integer variables.
+    pub fn integer_objective(&self) -> Result<i64, IlpError> {
+        let value = self
+            .evaluate_exact(&self.objective)
+            .ok_or(IlpError::NotInteger)?;
+        value.try_into().map_err(|_| IlpError::Overflow)
+    }
+
+    fn evaluate(&self, expr: &LinearExpr) -> f64 {
+        expr.terms()
+            .iter()
+            .map(|&(var, coefficient)| coefficient as f64 * self.value(var))
+            .sum()
+    }
+
+    fn evaluate_exact(&self, expr: &LinearExpr) -> Option<i128> {
+        expr.terms()
+            .iter()
+            .map(|&(var, coefficient)| match self.values[var.0] {
+                Value::Integer(value) => Some(coefficient as i128 * value as i128),
+                Value::Continuous(_) => None,
+            })
+            .sum()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_continuous() {
+        // maximise x + 2y with x >= 0 and 0 <= y <= 3,
+        // subject to x + y <= 4 and 2x + y >= 2
+        let mut model = Model::new(Direction::Maximise);
+        let x = model.continuous_var((0.0, f64::INFINITY));
+        let y = model.continuous_var((0.0, 3.0));
+        model.set_objective(x + 2 * y);
+        model.add_constraint(x + y, Comparison::Le, 4);
+        model.add_constraint(2 * x + y, Comparison::Ge, 2);
+
+        let solution = model.solve().expect("Should be solvable");
+        assert!((solution.objective() - 7.0).abs() < EPSILON);
+        assert!((solution.value(x) - 1.0).abs() < EPSILON);
+        assert!((solution.value(y) - 3.0).abs() < EPSILON);
+        assert_eq!(solution.integer_objective(), Err(IlpError::NotInteger));
+    }
+
+    #[test]
+    fn test_integer() {
+        // first machine of day 10
+        let mut model = Model::new(Direction::Minimise);
+        let b: Vec<Var> = (0..6).map(|_| model.integer_var((0, 100))).collect();
+        model.set_objective(b.iter().copied().sum::<LinearExpr>());
+        model.add_constraint(b[4] + b[5], Comparison::Eq, 3);
+        model.add_constraint(b[1] + b[5], Comparison::Eq, 5);
+        model.add_constraint(b[2] + b[3] + b[4], Comparison::Eq, 4);
+        model.add_constraint(b[0] + b[1] + b[3], Comparison::Eq, 7);
+
+        let solution = model.solve().expect("Should be solvable");
+        assert_eq!(solution.integer_objective(), Ok(10));
+        assert_eq!(solution.integer(b[4]) + solution.integer(b[5]), 3);
+    }
+
+    #[test]
+    fn test_errors() {
+        let mut model = Model::new(Direction::Minimise);
+        let x = model.integer_var((0, 10));
+        model.add_constraint(x, Comparison::Ge, 11);
+        assert_eq!(model.solve().unwrap_err(), IlpError::Infeasible);
+
+        let mut model = Model::new(Direction::Maximise);
+        let x = model.continuous_var((0.0, f64::INFINITY));
+        model.set_objective(x);
+        assert_eq!(model.solve().unwrap_err(), IlpError::Unbounded);
+    }
+
+    #[test]
+    fn test_overflow() {
+        let mut model = Model::new(Direction::Minimise);
+        let x = model.integer_var((0, 10));
+        let y = model.integer_var((0, 10));
+        model.set_objective(i64::MAX * (2 * x + y));
+        assert!(model.objective.overflowed());
+        assert_eq!(model.solve().unwrap_err(), IlpError::Overflow);
+
+        let mut model = Model::new(Direction::Maximise);
+        let x = model.integer_var((0, 10));
+        model.set_objective(i64::MAX * x + x);
+        assert_eq!(model.solve().unwrap_err(), IlpError::Overflow);
+
+        let mut model = Model::new(Direction::Maximise);
+        let x = model.integer_var((0, 10));
+        model.set_objective(i64::MAX * x);
+        let solution = model.solve().expect("Should be solvable");
+        assert_eq!(solution.integer(x), 10);
+        assert_eq!(solution.integer_objective(), Err(IlpError::Overflow));
+    }
+}
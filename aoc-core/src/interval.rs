@@ -0,0 +1,352 @@
+/*
+    Sets of inclusive integer intervals, kept sorted, disjoint and non-adjacent.
+
+    It started as the sort-and-merge loop of day 5, that was written twice.
+    Membership queries are binary searches on the merged ranges, set operations
+    are linear merges of the two sorted lists.
+
+    A range with its start after its end is empty: it is ignored when inserted,
+    removed or collected, and it is contained in any set.
+*/
+
+use std::fmt::Debug;
+
+pub trait IntervalBound: Copy + Ord + Debug {
+    const MIN: Self;
+    const MAX: Self;
+
+    fn checked_next(self) -> Option<Self>;
+    fn checked_prev(self) -> Option<Self>;
+    // number of values in [start, end], assuming start <= end
+    fn span(start: Self, end: Self) -> u128;
+}
+
+macro_rules! impl_interval_bound {
+    ($($t:ty),*) => {
+        $(
+            impl IntervalBound for $t {
+                const MIN: Self = <$t>::MIN;
+                const MAX: Self = <$t>::MAX;
+
+                fn checked_next(self) -> Option<Self> {
+                    self.checked_add(1)
+                }
+
+                fn checked_prev(self) -> Option<Self> {
+                    self.checked_sub(1)
+                }
+
+                fn span(start: Self, end: Self) -> u128 {
+                    (end as i128 - start as i128) as u128 + 1
+                }
+            }
+        )*
+    };
+}
+
+impl_interval_bound!(u8, u16, u32, u64, usize, i8, i16, i32, i64, isize);
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IntervalSet<T: IntervalBound> {
+    // sorted by start, disjoint, and never touching each other
+    ranges: Vec<(T, T)>,
+}
+
+impl<T: IntervalBound> Default for IntervalSet<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+// true if the range starting at next_start must be merged in the one ending at current_end
+fn touches<T: IntervalBound>(current_end: T, next_start: T) -> bool {
+    match current_end.checked_next() {
+        Some(after_end) => next_start <= after_end,
+        None => true,
+    }
+}
+
+impl<T: IntervalBound> IntervalSet<T> {
+    pub fn new() -> Self {
+        IntervalSet { ranges: Vec::new() }
+    }
+
+    // Sort and merge arbitrary ranges, in O(n log n).
+    fn from_unsorted(mut ranges: Vec<(T, T)>) -> Self {
+        ranges.retain(|(start, end)| start <= end);
+        ranges.sort_unstable_by_key(|(start, _end)| *start);
+
+        let mut merged: Vec<(T, T)> = Vec::with_capacity(ranges.len());
+        for (start, end) in ranges {
+            match merged.last_mut() {
+                Some((_, current_end)) if touches(*current_end, start) => {
+                    // extend the current range, only if higher end
+                    if end > *current_end {
+                        *current_end = end;
+                    }
+                }
+                _ => merged.push((start, end)),
+            }
+        }
+        IntervalSet { ranges: merged }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.ranges.is_empty()
+    }
+
+    // Number of merged ranges.
+    pub fn nb_ranges(&self) -> usize {
+        self.ranges.len()
+    }
+
+    // Total number of values covered by the set.
+    pub fn len(&self) -> u128 {
+        self.ranges
+            .iter()
+            .map(|&(start, end)| T::span(start, end))
+            .sum()
+    }
+
+    pub fn ranges(&self) -> &[(T, T)] {
+        &self.ranges
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (T, T)> + '_ {
+        self.ranges.iter().copied()
+    }
+
+    pub fn first(&self) -> Option<T> {
+        self.ranges.first().map(|(start, _end)| *start)
+    }
+
+    pub fn last(&self) -> Option<T> {
+        self.ranges.last().map(|(_start, end)| *end)
+    }
+
+    pub fn contains(&self, value: T) -> bool {
+        match self.ranges.partition_point(|&(start, _end)| start <= value) {
+            0 => false, // before first range
+            idx => value <= self.ranges[idx - 1].1,
+        }
+    }
+
+    // True if every value of [start, end] is in the set.
+    pub fn contains_range(&self, start: T, end: T) -> bool {
+        if start > end {
+            return true;
+        }
+        match self.ranges.partition_point(|&(s, _e)| s <= start) {
+            0 => false,
+            idx => end <= self.ranges[idx - 1].1,
+        }
+    }
+
+    pub fn insert(&mut self, start: T, end: T) {
+        if start > end {
+            return;
+        }
+        // first range that could be merged with the new one
+        let first = self.ranges.partition_point(|&(_s, e)| !touches(e, start));
+        // first range strictly after the new one, without touching it
+        let last = self.ranges.partition_point(|&(s, _e)| touches(end, s));
+
+        let mut merged = (start, end);
+        if first < last {
+            merged.0 = merged.0.min(self.ranges[first].0);
+            merged.1 = merged.1.max(self.ranges[last - 1].1);
+        }
+        self.ranges.splice(first..last, [merged]);
+    }
+
+    pub fn remove(&mut self, start: T, end: T) {
+        if start > end {
+            return;
+        }
+        // ranges overlapping [start, end] are in first..last
+        let first = self.ranges.partition_point(|&(_s, e)| e < start);
+        let last = self.ranges.partition_point(|&(s, _e)| s <= end);
+        if first >= last {
+            return;
+        }
+
+        let mut remaining = Vec::with_capacity(2);
+        let (first_start, _) = self.ranges[first];
+        if first_start < start {
+            remaining.push((first_start, start.checked_prev().expect("start > MIN")));
+        }
+        let (_, last_end) = self.ranges[last - 1];
+        if last_end > end {
+            remaining.push((end.checked_next().expect("end < MAX"), last_end));
+        }
+        self.ranges.splice(first..last, remaining);
+    }
+
+    pub fn union(&self, other: &Self) -> Self {
+        let mut ranges = Vec::with_capacity(self.ranges.len() + other.ranges.len());
+        ranges.extend_from_slice(&self.ranges);
+        ranges.extend_from_slice(&other.ranges);
+        Self::from_unsorted(ranges)
+    }
+
+    pub fn intersection(&self, other: &Self) -> Self {
+        let mut ranges = Vec::new();
+        let (mut i, mut j) = (0, 0);
+        while i < self.ranges.len() && j < other.ranges.len() {
+            let (start_a, end_a) = self.ranges[i];
+            let (start_b, end_b) = other.ranges[j];
+            let start = start_a.max(start_b);
+            let end = end_a.min(end_b);
+            if start <= end {
+                ranges.push((start, end));
+            }
+            // move forward the range that finishes first
+            if end_a < end_b {
+                i += 1;
+            } else {
+                j += 1;
+            }
+        }
+        IntervalSet { ranges }
+    }
+
+    pub fn difference(&self, other: &Self) -> Self {
+        self.intersection(&other.complement(T::MIN, T::MAX))
+    }
+
+    // Values of [lower, upper] that are not in the set, none if lower > upper.
+    pub fn complement(&self, lower: T, upper: T) -> Self {
+        let mut ranges = Vec::new();
+        if lower > upper {
+            return IntervalSet { ranges };
+        }
+        let mut cursor = Some(lower);
+        for &(start, end) in self.ranges.iter() {
+            let Some(current) = cursor else {
+                break;
+            };
+            if start > upper {
+                break;
+            }
+            if start > current {
+                ranges.push((current, start.checked_prev().expect("start > MIN")));
+            }
+            if end >= current {
+                cursor = end.checked_next();
+            }
+        }
+        if let Some(current) = cursor
+            && current <= upper
+        {
+            ranges.push((current, upper));
+        }
+        IntervalSet { ranges }
+    }
+}
+
+impl<T: IntervalBound> FromIterator<(T, T)> for IntervalSet<T> {
+    fn from_iter<I: IntoIterator<Item = (T, T)>>(iter: I) -> Self {
+        Self::from_unsorted(iter.into_iter().collect())
+    }
+}
+
+impl<T: IntervalBound> Extend<(T, T)> for IntervalSet<T> {
+    fn extend<I: IntoIterator<Item = (T, T)>>(&mut self, iter: I) {
+        let mut ranges = std::mem::take(&mut self.ranges);
+        ranges.extend(iter);
+        *self = Self::from_unsorted(ranges);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn set(ranges: &[(u64, u64)]) -> IntervalSet<u64> {
+        ranges.iter().copied().collect()
+    }
+
+    #[test]
+    fn test_from_iter_merges() {
+        let s = set(&[(3, 5), (10, 14), (16, 20), (12, 18)]);
+        assert_eq!(s.ranges(), &[(3, 5), (10, 20)]);
+        assert_eq!(s.len(), 14);
+        // adjacent ranges are merged too
+        assert_eq!(set(&[(1, 2), (3, 4)]).ranges(), &[(1, 4)]);
+    }
+
+    #[test]
+    fn test_contains() {
+        let s = set(&[(3, 5), (10, 14), (16, 20), (12, 18)]);
+        let fresh: Vec<u64> = [1, 5, 8, 11, 17, 32]
+            .into_iter()
+            .filter(|&i| s.contains(i))
+            .collect();
+        assert_eq!(fresh, vec![5, 11, 17]);
+        assert!(s.contains_range(11, 19));
+        assert!(!s.contains_range(4, 10));
+    }
+
+    #[test]
+    fn test_insert() {
+        let mut s = set(&[(1, 2), (6, 8), (12, 14)]);
+        s.insert(4, 4);
+        assert_eq!(s.ranges(), &[(1, 2), (4, 4), (6, 8), (12, 14)]);
+        s.insert(3, 10);
+        assert_eq!(s.ranges(), &[(1, 10), (12, 14)]);
+        s.insert(11, 11);
+        assert_eq!(s.ranges(), &[(1, 14)]);
+        s.insert(20, u64::MAX);
+        assert_eq!(s.ranges(), &[(1, 14), (20, u64::MAX)]);
+    }
+
+    #[test]
+    fn test_remove() {
+        let mut s = set(&[(1, 10), (20, 30)]);
+        s.remove(5, 6);
+        assert_eq!(s.ranges(), &[(1, 4), (7, 10), (20, 30)]);
+        s.remove(9, 25);
+        assert_eq!(s.ranges(), &[(1, 4), (7, 8), (26, 30)]);
+        s.remove(0, 100);
+        assert!(s.is_empty());
+    }
+
+    #[test]
+    fn test_set_operations() {
+        let a = set(&[(1, 10), (20, 30)]);
+        let b = set(&[(5, 25)]);
+        assert_eq!(a.union(&b).ranges(), &[(1, 30)]);
+        assert_eq!(a.intersection(&b).ranges(), &[(5, 10), (20, 25)]);
+        assert_eq!(a.difference(&b).ranges(), &[(1, 4), (26, 30)]);
+        assert_eq!(b.difference(&a).ranges(), &[(11, 19)]);
+    }
+
+    #[test]
+    fn test_complement() {
+        let a = set(&[(1, 10), (20, 30)]);
+        assert_eq!(a.complement(0, 40).ranges(), &[(0, 0), (11, 19), (31, 40)]);
+        assert_eq!(a.complement(5, 25).ranges(), &[(11, 19)]);
+        assert_eq!(a.complement(2, 8).ranges(), &[]);
+        let full = set(&[(0, u64::MAX)]);
+        assert!(full.complement(0, u64::MAX).is_empty());
+        assert_eq!(full.len(), 1 << 64);
+    }
+
+    #[test]
+    fn test_inverted_ranges() {
+        let mut s = set(&[(3, 5), (9, 2), (10, 14)]);
+        assert_eq!(s.ranges(), &[(3, 5), (10, 14)]);
+        s.insert(8, 6);
+        s.remove(12, 4);
+        assert_eq!(s.ranges(), &[(3, 5), (10, 14)]);
+        assert!(s.contains_range(7, 6));
+        assert!(s.complement(20, 0).is_empty());
+    }
+
+    #[test]
+    fn test_signed() {
+        let s: IntervalSet<i32> = [(-5, -1), (0, 3), (10, 12)].into_iter().collect();
+        assert_eq!(s.ranges(), &[(-5, 3), (10, 12)]);
+        assert_eq!(s.complement(i32::MIN, i32::MAX).nb_ranges(), 3);
+    }
+}
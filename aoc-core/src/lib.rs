@@ -0,0 +1,28 @@
+/*
+    What every year shares: the runner, the registry of the days, and the helpers
+    that were useful more than once, from parsing to counting paths in a graph.
+
+    A year is its own crate, listing its days in a registry and calling runner::main.
+    The helpers depending on a bigger crate are behind the grid, graph and ilp features,
+    and rayon is behind the parallel one.
+*/
+
+pub mod answer;
+pub mod arithmetic;
+pub mod bench;
+pub mod error;
+pub mod generate;
+#[cfg(feature = "graph")]
+pub mod graph;
+#[cfg(feature = "ilp")]
+pub mod ilp;
+pub mod interval;
+pub mod ocr;
+pub mod parallel;
+pub mod parsers;
+pub mod registry;
+pub mod report;
+pub mod runner;
+pub mod spatial;
+pub mod trace;
+pub mod visualize;
@@ -0,0 +1,130 @@
+/*
+    Reading the block letters some puzzles answer with, like:
+
+    .##..###..####
+    #..#.#..#.#...
+    #..#.###..###.
+    ####.#..#.#...
+    #..#.#..#.#...
+    #..#.###..####
+
+    Only the 6 rows high font is known, and only the letters seen in past puzzles.
+    The lit pixels can be # or █, anything else is dark.
+*/
+
+const HEIGHT: usize = 6;
+
+const GLYPHS: [(char, [&str; HEIGHT]); 18] = [
+    ('A', [".##.", "#..#", "#..#", "####", "#..#", "#..#"]),
+    ('B', ["###.", "#..#", "###.", "#..#", "#..#", "###."]),
+    ('C', [".##.", "#..#", "#...", "#...", "#..#", ".##."]),
+    ('E', ["####", "#...", "###.", "#...", "#...", "####"]),
+    ('F', ["####", "#...", "###.", "#...", "#...", "#..."]),
+    ('G', [".##.", "#..#", "#...", "#.##", "#..#", ".###"]),
+    ('H', ["#..#", "#..#", "####", "#..#", "#..#", "#..#"]),
+    ('I', ["###", ".#.", ".#.", ".#.", ".#.", "###"]),
+    ('J', ["..##", "...#", "...#", "...#", "#..#", ".##."]),
+    ('K', ["#..#", "#.#.", "##..", "#.#.", "#.#.", "#..#"]),
+    ('L', ["#...", "#...", "#...", "#...", "#...", "####"]),
+    ('O', [".##.", "#..#", "#..#", "#..#", "#..#", ".##."]),
+    ('P', ["###.", "#..#", "#..#", "###.", "#...", "#..."]),
+    ('R', ["###.", "#..#", "#..#", "###.", "#.#.", "#..#"]),
+    ('S', [".###", "#...", "#...", ".##.", "...#", "###."]),
+    ('U', ["#..#", "#..#", "#..#", "#..#", "#..#", ".##."]),
+    ('Y', ["#...#", "#...#", ".#.#.", "..#..", "..#..", "..#.."]),
+    ('Z', ["####", "...#", "..#.", ".#..", "#...", "####"]),
+];
+
+fn is_lit(c: char) -> bool {
+    c == '#' || c == '█'
+}
+
+// The letters of the art, or None if it has a glyph that isn't known.
+pub fn ocr(art: &str) -> Option<String> {
+    let rows: Vec<Vec<bool>> = art
+        .lines()
+        .map(|line| line.chars().map(is_lit).collect::<Vec<_>>())
+        .skip_while(|row| !row.contains(&true))
+        .collect();
+    let nb_rows = rows.iter().rposition(|row| row.contains(&true))? + 1;
+    if nb_rows != HEIGHT {
+        return None;
+    }
+    let width = rows.iter().map(Vec::len).max().unwrap_or(0);
+    let pixel = |row: usize, col: usize| rows[row].get(col).copied().unwrap_or(false);
+    let is_blank_column = |col: usize| (0..HEIGHT).all(|row| !pixel(row, col));
+
+    let mut text = String::new();
+    let mut col = 0;
+    while col < width {
+        if is_blank_column(col) {
+            col += 1;
+            continue;
+        }
+        // the widest glyphs first, the Y doesn't leave an empty column after it
+        let (letter, glyph_width) = GLYPHS
+            .iter()
+            .filter(|(_, glyph)| {
+                glyph.iter().enumerate().all(|(row, line)| {
+                    line.chars()
+                        .enumerate()
+                        .all(|(offset, c)| is_lit(c) == pixel(row, col + offset))
+                })
+            })
+            .map(|(letter, glyph)| (*letter, glyph[0].len()))
+            .max_by_key(|&(_, glyph_width)| glyph_width)?;
+        text.push(letter);
+        col += glyph_width;
+    }
+    Some(text)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn render(text: &str) -> String {
+        (0..HEIGHT)
+            .map(|row| {
+                text.chars()
+                    .map(|letter| {
+                        let (_, glyph) = GLYPHS.iter().find(|(c, _)| *c == letter).unwrap();
+                        format!("{:.<5}", glyph[row])
+                    })
+                    .collect::<String>()
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    #[test]
+    fn test_every_letter() {
+        let alphabet: String = GLYPHS.iter().map(|(letter, _)| *letter).collect();
+        assert_eq!(ocr(&render(&alphabet)), Some(alphabet));
+    }
+
+    #[test]
+    fn test_ocr() {
+        let art = "
+.##..###..####
+#..#.#..#.#...
+#..#.###..###.
+####.#..#.#...
+#..#.#..#.#...
+#..#.###..####
+";
+        assert_eq!(ocr(art), Some("ABE".to_string()));
+        assert_eq!(
+            ocr(&art.replace('#', "█").replace('.', " ")),
+            Some("ABE".to_string())
+        );
+        assert_eq!(ocr(&render("YZY")), Some("YZY".to_string()));
+    }
+
+    #[test]
+    fn test_unknown_glyphs() {
+        assert_eq!(ocr("#\n#\n#"), None);
+        assert_eq!(ocr(""), None);
+        assert_eq!(ocr(&render("A").replace(".##.", "#..#")), None);
+    }
+}
@@ -0,0 +1,51 @@
+/*
+    rayon with the parallel feature, plain iterators without it,
+    for the targets without threads like wasm32-unknown-unknown.
+
+    The days import the prelude and keep calling par_iter and par_bridge.
+    Only the adaptors that rayon and the std iterators have in common can follow,
+    map and collect for example, but not rayon's reduce with an identity.
+*/
+
+#[cfg(feature = "parallel")]
+pub mod prelude {
+    pub use rayon::iter::{IntoParallelRefIterator, ParallelBridge, ParallelIterator};
+}
+
+#[cfg(not(feature = "parallel"))]
+pub mod prelude {
+    pub trait IntoParallelRefIterator<'data> {
+        type Iter: Iterator;
+
+        fn par_iter(&'data self) -> Self::Iter;
+    }
+
+    impl<'data, T: 'data> IntoParallelRefIterator<'data> for [T] {
+        type Iter = std::slice::Iter<'data, T>;
+
+        fn par_iter(&'data self) -> Self::Iter {
+            self.iter()
+        }
+    }
+
+    pub trait ParallelBridge: Iterator + Sized {
+        fn par_bridge(self) -> Self {
+            self
+        }
+    }
+
+    impl<I: Iterator> ParallelBridge for I {}
+}
+
+#[cfg(test)]
+mod tests {
+    use super::prelude::*;
+
+    #[test]
+    fn test_same_results() {
+        let numbers: Vec<u64> = (1..=100).collect();
+        let squares: Vec<u64> = numbers.par_iter().map(|n| n * n).collect();
+        assert_eq!(squares[9], 100);
+        assert_eq!(numbers.iter().par_bridge().map(|n| n % 7).max(), Some(6));
+    }
+}
@@ -0,0 +1,244 @@
+/*
+    nom combinators for the input shapes that come back every day.
+
+    Lines are separated by line_ending, so CRLF inputs are fine, and a trailing
+    newline is simply left unparsed. Blank-line separated sections are split before
+    parsing, with sections(), so each part of the input can have its own parser.
+
+    parse_input() runs a parser on a whole input and turns the nom errors into
+    InputError, with the line and column where the parsing stopped.
+*/
+
+use nom::{
+    AsChar, Input, Parser,
+    character::complete::{char, line_ending, multispace0},
+    combinator::map,
+    error::ParseError,
+    multi::separated_list1,
+    sequence::{delimited, separated_pair},
+};
+
+use crate::error::InputError;
+
+// Run the parser on the whole input, only whitespace can be left after it.
+pub fn parse_input<'a, O, P>(mut parser: P, data: &'a str) -> Result<O, InputError>
+where
+    P: Parser<&'a str, Output = O, Error = nom::error::Error<&'a str>>,
+{
+    match parser.parse(data) {
+        Ok((rest, output)) if rest.trim().is_empty() => Ok(output),
+        Ok((rest, _)) => Err(InputError::parse(data, rest)),
+        Err(nom::Err::Error(error) | nom::Err::Failure(error)) => {
+            Err(InputError::parse(data, error.input))
+        }
+        Err(nom::Err::Incomplete(_)) => Err(InputError::parse(data, "")),
+    }
+}
+
+// One item per line.
+pub fn lines<I, O, E, P>(item: P) -> impl Parser<I, Output = Vec<O>, Error = E>
+where
+    I: Input + Clone + nom::Compare<&'static str>,
+    E: ParseError<I>,
+    P: Parser<I, Output = O, Error = E>,
+{
+    separated_list1(line_ending, item)
+}
+
+// Items separated by commas, optionally followed by whitespace or a newline.
+pub fn comma_separated<I, O, E, P>(item: P) -> impl Parser<I, Output = Vec<O>, Error = E>
+where
+    I: Input + Clone,
+    <I as Input>::Item: AsChar,
+    E: ParseError<I>,
+    P: Parser<I, Output = O, Error = E>,
+{
+    separated_list1((char(','), multispace0), item)
+}
+
+// a-b, such as 11-22
+pub fn range<I, O, E, P>(bound: P) -> impl Parser<I, Output = (O, O), Error = E>
+where
+    I: Input + Clone,
+    <I as Input>::Item: AsChar,
+    E: ParseError<I>,
+    P: Parser<I, Output = O, Error = E> + Clone,
+{
+    separated_pair(bound.clone(), char('-'), bound)
+}
+
+// x,y
+pub fn point2<I, O, E, P>(coordinate: P) -> impl Parser<I, Output = (O, O), Error = E>
+where
+    I: Input + Clone,
+    <I as Input>::Item: AsChar,
+    E: ParseError<I>,
+    P: Parser<I, Output = O, Error = E> + Clone,
+{
+    separated_pair(coordinate.clone(), char(','), coordinate)
+}
+
+// x,y,z
+pub fn point3<I, O, E, P>(coordinate: P) -> impl Parser<I, Output = (O, O, O), Error = E>
+where
+    I: Input + Clone,
+    <I as Input>::Item: AsChar,
+    E: ParseError<I>,
+    P: Parser<I, Output = O, Error = E> + Clone,
+{
+    map(
+        (
+            coordinate.clone(),
+            char(','),
+            coordinate.clone(),
+            char(','),
+            coordinate,
+        ),
+        |(x, _, y, _, z)| (x, y, z),
+    )
+}
+
+// [item], (item), {item}…
+pub fn bracketed<I, O, E, P>(
+    open: char,
+    item: P,
+    close: char,
+) -> impl Parser<I, Output = O, Error = E>
+where
+    I: Input + Clone,
+    <I as Input>::Item: AsChar,
+    E: ParseError<I>,
+    P: Parser<I, Output = O, Error = E>,
+{
+    delimited(char(open), item, char(close))
+}
+
+// (1,2,3), {4,5,6}…
+pub fn bracketed_list<I, O, E, P>(
+    open: char,
+    item: P,
+    close: char,
+) -> impl Parser<I, Output = Vec<O>, Error = E>
+where
+    I: Input + Clone,
+    <I as Input>::Item: AsChar,
+    E: ParseError<I>,
+    P: Parser<I, Output = O, Error = E>,
+{
+    bracketed(open, separated_list1(char(','), item), close)
+}
+
+// A rectangular grid of cells, one row per line.
+#[cfg(feature = "grid")]
+pub fn grid<I, O, E, P>(cell: P) -> impl Parser<I, Output = ndarray::Array2<O>, Error = E>
+where
+    I: Input + Clone + nom::Compare<&'static str>,
+    O: Clone,
+    E: ParseError<I>,
+    P: Parser<I, Output = O, Error = E>,
+{
+    use ndarray::Array2;
+    use nom::{combinator::verify, multi::many1};
+
+    map(
+        verify(
+            separated_list1(line_ending, many1(cell)),
+            |rows: &Vec<Vec<O>>| rows.iter().all(|row| row.len() == rows[0].len()),
+        ),
+        |rows| {
+            let nb_rows = rows.len();
+            let nb_cols = rows.first().map_or(0, |row| row.len());
+            Array2::from_shape_fn((nb_rows, nb_cols), |(row, col)| rows[row][col].clone())
+        },
+    )
+}
+
+// Split the input on blank lines, ignoring empty sections.
+pub fn sections(data: &str) -> Vec<&str> {
+    let mut sections = Vec::new();
+    let mut section_start = 0;
+    let mut position = 0;
+    for line in data.split_inclusive('\n') {
+        if line.trim_end_matches(['\r', '\n']).is_empty() {
+            let section = data[section_start..position].trim_end_matches(['\r', '\n']);
+            if !section.is_empty() {
+                sections.push(section);
+            }
+            section_start = position + line.len();
+        }
+        position += line.len();
+    }
+    let section = data[section_start..].trim_end_matches(['\r', '\n']);
+    if !section.is_empty() {
+        sections.push(section);
+    }
+    sections
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use nom::{IResult, character::complete::u64};
+
+    #[test]
+    fn test_lines() {
+        let result: IResult<&str, Vec<u64>> = lines(u64).parse("1\r\n2\n3\n");
+        assert_eq!(result, Ok(("\n", vec![1, 2, 3])));
+    }
+
+    #[test]
+    fn test_comma_separated_ranges() {
+        let result: IResult<&str, Vec<(u64, u64)>> =
+            comma_separated(range(u64)).parse("11-22,95-115,\n998-1012");
+        assert_eq!(result, Ok(("", vec![(11, 22), (95, 115), (998, 1012)])));
+    }
+
+    #[test]
+    fn test_points() {
+        let result: IResult<&str, (u64, u64)> = point2(u64).parse("7,1");
+        assert_eq!(result, Ok(("", (7, 1))));
+        let result: IResult<&str, (u64, u64, u64)> = point3(u64).parse("162,817,812");
+        assert_eq!(result, Ok(("", (162, 817, 812))));
+    }
+
+    #[test]
+    fn test_bracketed_list() {
+        let result: IResult<&str, Vec<u64>> = bracketed_list('{', u64, '}').parse("{3,5,4,7}");
+        assert_eq!(result, Ok(("", vec![3, 5, 4, 7])));
+    }
+
+    #[test]
+    #[cfg(feature = "grid")]
+    fn test_grid() {
+        let result: IResult<&str, ndarray::Array2<char>> =
+            grid(nom::character::complete::one_of(".#")).parse(".#.\n##.\n");
+        let (_, cells) = result.expect("Valid grid");
+        assert_eq!(cells.dim(), (2, 3));
+        assert_eq!(cells[(1, 0)], '#');
+        let result: IResult<&str, ndarray::Array2<char>> =
+            grid(nom::character::complete::one_of(".#")).parse(".#.\n##");
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_parse_input() {
+        assert_eq!(parse_input(lines(u64), "1\n2\n3\n"), Ok(vec![1, 2, 3]));
+        assert_eq!(
+            parse_input(lines(u64), "1\n2\nx\n"),
+            Err(InputError::Parse {
+                line: 3,
+                column: 1,
+                near: "x".to_string()
+            })
+        );
+        assert!(parse_input(lines(u64), "").is_err());
+    }
+
+    #[test]
+    fn test_sections() {
+        assert_eq!(sections("1-2\n3-4\n\n5\n6\n"), vec!["1-2\n3-4", "5\n6"]);
+        assert_eq!(sections("1-2\r\n\r\n5\r\n"), vec!["1-2", "5"]);
+        assert_eq!(sections("a\n\n\n\nb"), vec!["a", "b"]);
+        assert_eq!(sections(""), Vec::<&str>::new());
+    }
+}
@@ -0,0 +1,146 @@
+/*
+    The days a runner knows about, from every year linked into it.
+
+    A day is addressed as 2025/9, or as 9 for the latest year having a day 9.
+    The leading zero is optional, 2025/09 is fine too.
+*/
+
+use crate::answer::Answer;
+use crate::error::InputError;
+use crate::generate::Generator;
+use crate::visualize::Frame;
+
+pub type Part = fn(&str) -> Result<Answer, InputError>;
+
+pub type Visualizer = fn(&str) -> Result<Vec<Frame>, InputError>;
+
+// A table or any text detailing the steps to the answers.
+pub type Explainer = fn(&str) -> Result<String, InputError>;
+
+pub struct Day {
+    pub year: u16,
+    // 09
+    pub name: &'static str,
+    pub input: &'static str,
+    pub part_1: Part,
+    pub part_2: Part,
+    pub generate: Generator,
+    pub visualize: Option<Visualizer>,
+    pub explain: Option<Explainer>,
+}
+
+impl Day {
+    // 2025/09
+    pub fn label(&self) -> String {
+        format!("{}/{}", self.year, self.name)
+    }
+}
+
+pub struct Registry {
+    // by year, then by day
+    days: Vec<&'static Day>,
+}
+
+impl Registry {
+    pub fn new(years: &[&'static [Day]]) -> Self {
+        let mut days: Vec<&'static Day> = years.iter().flat_map(|days| days.iter()).collect();
+        days.sort_by_key(|day| (day.year, day.name));
+        Registry { days }
+    }
+
+    pub fn days(&self) -> &[&'static Day] {
+        &self.days
+    }
+
+    pub fn find(&self, address: &str) -> Option<&'static Day> {
+        let (year, day) = match address.split_once('/') {
+            Some((year, day)) => (Some(year.parse::<u16>().ok()?), day),
+            None => (None, address),
+        };
+        let name = format!("{:02}", day.parse::<u32>().ok()?);
+        self.days
+            .iter()
+            .rev()
+            .find(|candidate| {
+                candidate.name == name && year.is_none_or(|year| candidate.year == year)
+            })
+            .copied()
+    }
+
+    // The answer as it would be typed on the website, for the bindings.
+    pub fn solve(&self, address: &str, part: u8, input: &str) -> Result<String, String> {
+        let day = self
+            .find(address)
+            .ok_or_else(|| format!("Unknown day: {}", address))?;
+        let solve = match part {
+            1 => day.part_1,
+            2 => day.part_2,
+            _ => return Err(format!("Unknown part: {}", part)),
+        };
+        solve(input)
+            .map(|answer| answer.text())
+            .map_err(|error| error.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::generate::Rng;
+
+    fn answer(data: &str) -> Result<Answer, InputError> {
+        match data {
+            "" => Err(InputError::invalid("Empty input")),
+            _ => Ok(Answer::Integer(42)),
+        }
+    }
+
+    fn generate(_rng: &mut Rng, _size: usize) -> String {
+        String::new()
+    }
+
+    const fn day(year: u16, name: &'static str) -> Day {
+        Day {
+            year,
+            name,
+            input: "",
+            part_1: answer,
+            part_2: answer,
+            generate,
+            visualize: None,
+            explain: None,
+        }
+    }
+
+    static YEAR_2024: [Day; 2] = [day(2024, "09"), day(2024, "25")];
+    static YEAR_2025: [Day; 2] = [day(2025, "01"), day(2025, "09")];
+
+    #[test]
+    fn test_find() {
+        let registry = Registry::new(&[&YEAR_2025, &YEAR_2024]);
+        assert_eq!(registry.days().len(), 4);
+        assert_eq!(
+            registry.find("2024/9").map(Day::label),
+            Some("2024/09".into())
+        );
+        assert_eq!(registry.find("9").map(Day::label), Some("2025/09".into()));
+        assert_eq!(registry.find("09").map(Day::label), Some("2025/09".into()));
+        assert_eq!(registry.find("25").map(Day::label), Some("2024/25".into()));
+        assert!(registry.find("2025/25").is_none());
+        assert!(registry.find("2023/9").is_none());
+        assert!(registry.find("nine").is_none());
+        assert!(registry.find("2025/").is_none());
+    }
+
+    #[test]
+    fn test_solve() {
+        let registry = Registry::new(&[&YEAR_2025]);
+        assert_eq!(registry.solve("9", 2, "input"), Ok("42".to_string()));
+        assert_eq!(
+            registry.solve("2025/1", 1, ""),
+            Err("Invalid input data: Empty input".to_string())
+        );
+        assert!(registry.solve("9", 3, "input").is_err());
+        assert!(registry.solve("2", 1, "input").is_err());
+    }
+}
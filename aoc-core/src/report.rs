@@ -0,0 +1,228 @@
+/*
+    The answers of a run, printed as JSON for other tools, and checked against
+    a verification file of known answers.
+
+    The verification file has one answer per line: the day, the part and the answer
+    as typed on the website, for example "2025/09 2 1474699155". Without the year,
+    "09 2 1474699155" is the answer of day 9 of any year. The art answers are
+    compared once read by the OCR. Empty lines and lines starting with # are ignored.
+
+    There is no serde here, the JSON is simple enough to be written by hand.
+*/
+
+use std::{collections::HashMap, time::Duration};
+
+use crate::answer::Answer;
+use crate::error::InputError;
+
+pub struct PartResult {
+    pub day: String,
+    pub part: u8,
+    pub result: Result<Answer, InputError>,
+    pub elapsed: Duration,
+}
+
+// The expected answers, by day and part.
+pub type Expected = HashMap<(String, u8), String>;
+
+fn day_key(day: &str) -> Option<String> {
+    // 9 and 09 are the same day
+    let number = |day: &str| {
+        day.parse::<u32>()
+            .ok()
+            .map(|number| format!("{:02}", number))
+    };
+    match day.split_once('/') {
+        Some((year, day)) => Some(format!("{}/{}", year.parse::<u16>().ok()?, number(day)?)),
+        None => number(day),
+    }
+}
+
+pub fn parse_expected(data: &str) -> Result<Expected, String> {
+    let mut expected = Expected::new();
+    for (index, line) in data.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        let invalid = || format!("Invalid verification line {}: {}", index + 1, line);
+        let mut fields = line.splitn(3, char::is_whitespace);
+        let day = fields.next().and_then(day_key).ok_or_else(invalid)?;
+        let part = fields
+            .next()
+            .and_then(|part| part.parse::<u8>().ok())
+            .filter(|part| (1..=2).contains(part))
+            .ok_or_else(invalid)?;
+        let answer = fields.next().map(str::trim).ok_or_else(invalid)?;
+        expected.insert((day, part), answer.to_string());
+    }
+    Ok(expected)
+}
+
+impl PartResult {
+    // The answer for this year first, then the one for any year.
+    pub fn expected<'a>(&self, expected: &'a Expected) -> Option<&'a String> {
+        let key = day_key(&self.day)?;
+        let day = key.rsplit('/').next().unwrap_or(&key).to_string();
+        expected
+            .get(&(key, self.part))
+            .or_else(|| expected.get(&(day, self.part)))
+    }
+
+    // None when the answer isn't known, an error is never correct.
+    pub fn is_correct(&self, expected: &Expected) -> Option<bool> {
+        let expected = self.expected(expected)?;
+        Some(matches!(&self.result, Ok(answer) if answer.text() == *expected))
+    }
+}
+
+fn json_string(text: &str) -> String {
+    let mut json = String::with_capacity(text.len() + 2);
+    json.push('"');
+    for c in text.chars() {
+        match c {
+            '"' => json.push_str("\\\""),
+            '\\' => json.push_str("\\\\"),
+            '\n' => json.push_str("\\n"),
+            '\r' => json.push_str("\\r"),
+            '\t' => json.push_str("\\t"),
+            c if (c as u32) < 0x20 => json.push_str(&format!("\\u{:04x}", c as u32)),
+            c => json.push(c),
+        }
+    }
+    json.push('"');
+    json
+}
+
+fn json_object(result: &PartResult, expected: Option<&Expected>) -> String {
+    let mut fields = vec![
+        format!("\"day\": {}", json_string(&result.day)),
+        format!("\"part\": {}", result.part),
+        format!("\"time_us\": {}", result.elapsed.as_micros()),
+    ];
+    match &result.result {
+        Ok(answer) => {
+            fields.push(format!("\"kind\": {}", json_string(answer.kind())));
+            // as a string, the big integers don't fit in the JSON numbers of most parsers
+            fields.push(format!("\"answer\": {}", json_string(&answer.text())));
+            if let Answer::Art(art) = answer {
+                fields.push(format!("\"art\": {}", json_string(art)));
+            }
+        }
+        Err(error) => fields.push(format!("\"error\": {}", json_string(&error.to_string()))),
+    }
+    if let Some(expected) = expected {
+        match result.expected(expected) {
+            Some(answer) => {
+                fields.push(format!("\"expected\": {}", json_string(answer)));
+                fields.push(format!(
+                    "\"correct\": {}",
+                    result.is_correct(expected) == Some(true)
+                ));
+            }
+            None => fields.push("\"expected\": null".to_string()),
+        }
+    }
+    format!("{{{}}}", fields.join(", "))
+}
+
+// A JSON array with one object per part.
+pub fn json(results: &[PartResult], expected: Option<&Expected>) -> String {
+    let objects: Vec<String> = results
+        .iter()
+        .map(|result| format!("  {}", json_object(result, expected)))
+        .collect();
+    format!("[\n{}\n]", objects.join(",\n"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn result(day: &str, part: u8, result: Result<Answer, InputError>) -> PartResult {
+        PartResult {
+            day: day.to_string(),
+            part,
+            result,
+            elapsed: Duration::from_micros(12),
+        }
+    }
+
+    #[test]
+    fn test_parse_expected() {
+        let expected =
+            parse_expected("# day part answer\n01 1 1147\n\n9 2 EZFZ\n2025/3 1 357\n").unwrap();
+        assert_eq!(expected.len(), 3);
+        assert_eq!(expected[&("09".to_string(), 2)], "EZFZ");
+        assert_eq!(expected[&("2025/03".to_string(), 1)], "357");
+        assert!(parse_expected("01 3 12").is_err());
+        assert!(parse_expected("01 1").is_err());
+        assert!(parse_expected("day 1 12").is_err());
+        assert!(parse_expected("2025/ 1 12").is_err());
+    }
+
+    #[test]
+    fn test_is_correct() {
+        let expected = parse_expected("01 1 1147\n01 2 EH").unwrap();
+        assert_eq!(
+            result("01", 1, Ok(Answer::Integer(1147))).is_correct(&expected),
+            Some(true)
+        );
+        assert_eq!(
+            result("1", 1, Ok(Answer::Integer(1148))).is_correct(&expected),
+            Some(false)
+        );
+        let art = "####.#..#\n#....#..#\n###..####\n#....#..#\n#....#..#\n####.#..#";
+        assert_eq!(
+            result("01", 2, Ok(Answer::art(art))).is_correct(&expected),
+            Some(true)
+        );
+        assert_eq!(
+            result("01", 2, Err(InputError::invalid("nope"))).is_correct(&expected),
+            Some(false)
+        );
+        assert_eq!(
+            result("02", 1, Ok(Answer::Integer(1))).is_correct(&expected),
+            None
+        );
+    }
+
+    #[test]
+    fn test_is_correct_by_year() {
+        let expected = parse_expected("2024/01 1 12\n01 1 1147").unwrap();
+        assert_eq!(
+            result("2024/01", 1, Ok(Answer::Integer(12))).is_correct(&expected),
+            Some(true)
+        );
+        assert_eq!(
+            result("2025/01", 1, Ok(Answer::Integer(1147))).is_correct(&expected),
+            Some(true)
+        );
+        assert_eq!(
+            result("2025/1", 1, Ok(Answer::Integer(12))).is_correct(&expected),
+            Some(false)
+        );
+    }
+
+    #[test]
+    fn test_json() {
+        let results = [
+            result("01", 1, Ok(Answer::from(u128::MAX))),
+            result("01", 2, Err(InputError::invalid("a \"quoted\" word"))),
+            result("02", 1, Ok(Answer::art("#.\n.#"))),
+        ];
+        assert_eq!(
+            json(&results, None),
+            r##"[
+  {"day": "01", "part": 1, "time_us": 12, "kind": "big_integer", "answer": "340282366920938463463374607431768211455"},
+  {"day": "01", "part": 2, "time_us": 12, "error": "Invalid input data: a \"quoted\" word"},
+  {"day": "02", "part": 1, "time_us": 12, "kind": "art", "answer": "#.\n.#", "art": "#.\n.#"}
+]"##
+        );
+        let expected = parse_expected("01 1 42").unwrap();
+        assert!(
+            json(&results[..1], Some(&expected)).contains(r#""expected": "42", "correct": false"#)
+        );
+        assert!(json(&results[2..], Some(&expected)).contains(r#""expected": null"#));
+    }
+}
@@ -0,0 +1,414 @@
+/*
+    The command line of every year: run the days, verify them, visualise, trace,
+    generate inputs and bench. A year only has to pass its days to main.
+*/
+
+use std::{path::PathBuf, process::ExitCode};
+
+use crate::{
+    answer::Answer,
+    bench,
+    generate::Rng,
+    registry::{Day, Registry},
+    report::{self, Expected, PartResult},
+    trace::{self, Level},
+    visualize::{self, Format},
+};
+
+fn solve_part(day: &Day, part: u8) -> PartResult {
+    let solve = if part == 1 { day.part_1 } else { day.part_2 };
+    let now = std::time::Instant::now();
+    let result = solve(day.input);
+    PartResult {
+        day: day.label(),
+        part,
+        result,
+        elapsed: now.elapsed(),
+    }
+}
+
+// The answer, or why there isn't one, and whether it's the expected one.
+fn print_result(result: &PartResult, expected: Option<&Expected>) {
+    let answer = match &result.result {
+        // the text read by the OCR, with the letters below it
+        Ok(answer @ Answer::Art(art)) => format!("{}\n{}", answer.text(), art),
+        Ok(answer) => answer.to_string(),
+        Err(error) => error.to_string(),
+    };
+    let verdict =
+        match expected.map(|expected| (result.is_correct(expected), result.expected(expected))) {
+            None | Some((None, _)) => String::new(),
+            Some((Some(true), _)) => " ✓".to_string(),
+            Some((Some(false), expected)) => {
+                format!(" ✗ expected {}", expected.expect("Known answer"))
+            }
+        };
+    println!(
+        "Day {}, part {} ({:?}):\t{}{}",
+        result.day, result.part, result.elapsed, answer, verdict
+    );
+}
+
+fn execute_day(day: &Day) {
+    for part in [1, 2] {
+        print_result(&solve_part(day, part), None);
+    }
+}
+
+struct VisualizeOptions {
+    day: &'static Day,
+    output_dir: PathBuf,
+    formats: Vec<Format>,
+    scale: usize,
+}
+
+struct TraceOptions {
+    day: &'static Day,
+    level: Level,
+}
+
+struct GenerateOptions {
+    day: &'static Day,
+    size: usize,
+    seed: u64,
+}
+
+struct BenchOptions {
+    days: Vec<&'static Day>,
+    sizes: Vec<usize>,
+    seed: u64,
+    nb_runs: usize,
+}
+
+#[derive(Default)]
+struct RunOptions {
+    json: bool,
+    verify: Option<PathBuf>,
+}
+
+struct ExplainOptions {
+    day: &'static Day,
+    input: Option<PathBuf>,
+}
+
+enum Command {
+    Run(RunOptions),
+    Explain(ExplainOptions),
+    Visualize(VisualizeOptions),
+    Trace(TraceOptions),
+    Generate(GenerateOptions),
+    Bench(BenchOptions),
+}
+
+const USAGE: &str = "Usage: aoc [--json] [--verify <answers file>]
+       aoc [--visualize <day> [--output <dir>] [--format <ascii|ppm|png|svg>]... [--scale <pixels>]]
+       aoc [--trace <day> [--trace-level <info|debug|trace>]]
+       aoc explain <day> [--input <file>]
+       aoc generate <day> [--size <size>] [--seed <seed>]
+       aoc bench [<day>]... [--sizes <size,size…>] [--seed <seed>] [--runs <runs>]
+A day is 2025/9, or 9 for the latest year.";
+
+const DEFAULT_SEED: u64 = 2025;
+
+fn parse_day(registry: &Registry, name: &str) -> Result<&'static Day, String> {
+    registry
+        .find(name)
+        .ok_or_else(|| format!("Unknown day: {}", name))
+}
+
+fn parse_number<T: std::str::FromStr>(name: &str, what: &str) -> Result<T, String> {
+    name.parse()
+        .map_err(|_| format!("Invalid {}: {}", what, name))
+}
+
+fn parse_explain_args(registry: &Registry, args: &[String]) -> Result<Command, String> {
+    let mut args = args.iter();
+    let day = parse_day(registry, args.next().ok_or("Missing day to explain")?)?;
+    let mut input = None;
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--input" => {
+                let path = args
+                    .next()
+                    .ok_or_else(|| format!("Missing value for {}", arg))?;
+                input = Some(PathBuf::from(path));
+            }
+            _ => return Err(format!("Unknown argument: {}", arg)),
+        }
+    }
+    Ok(Command::Explain(ExplainOptions { day, input }))
+}
+
+fn parse_generate_args(registry: &Registry, args: &[String]) -> Result<Command, String> {
+    let mut args = args.iter();
+    let day = parse_day(registry, args.next().ok_or("Missing day to generate")?)?;
+    let mut size = 100;
+    let mut seed = DEFAULT_SEED;
+    while let Some(arg) = args.next() {
+        let mut value = || {
+            args.next()
+                .ok_or_else(|| format!("Missing value for {}", arg))
+        };
+        match arg.as_str() {
+            "--size" => size = parse_number(value()?, "size")?,
+            "--seed" => seed = parse_number(value()?, "seed")?,
+            _ => return Err(format!("Unknown argument: {}", arg)),
+        }
+    }
+    Ok(Command::Generate(GenerateOptions { day, size, seed }))
+}
+
+fn parse_bench_args(registry: &Registry, args: &[String]) -> Result<Command, String> {
+    let mut days = Vec::new();
+    let mut sizes = bench::DEFAULT_SIZES.to_vec();
+    let mut seed = DEFAULT_SEED;
+    let mut nb_runs = 3;
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        let mut value = || {
+            args.next()
+                .ok_or_else(|| format!("Missing value for {}", arg))
+        };
+        match arg.as_str() {
+            "--sizes" => {
+                sizes = value()?
+                    .split(',')
+                    .map(|size| parse_number(size, "size"))
+                    .collect::<Result<_, _>>()?
+            }
+            "--seed" => seed = parse_number(value()?, "seed")?,
+            "--runs" => nb_runs = parse_number(value()?, "number of runs")?,
+            name => days.push(parse_day(registry, name)?),
+        }
+    }
+    if days.is_empty() {
+        days.extend(registry.days());
+    }
+    Ok(Command::Bench(BenchOptions {
+        days,
+        sizes,
+        seed,
+        nb_runs,
+    }))
+}
+
+fn parse_args(registry: &Registry, args: &[String]) -> Result<Command, String> {
+    match args.first().map(String::as_str) {
+        None => return Ok(Command::Run(RunOptions::default())),
+        Some("explain") => return parse_explain_args(registry, &args[1..]),
+        Some("generate") => return parse_generate_args(registry, &args[1..]),
+        Some("bench") => return parse_bench_args(registry, &args[1..]),
+        _ => {}
+    }
+
+    let mut day = None;
+    let mut trace_day = None;
+    let mut trace_level = Level::Debug;
+    let mut output_dir = None;
+    let mut formats = Vec::new();
+    let mut scale = 4;
+    let mut run_options = RunOptions::default();
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        let mut value = || {
+            args.next()
+                .ok_or_else(|| format!("Missing value for {}", arg))
+        };
+        match arg.as_str() {
+            "--json" => run_options.json = true,
+            "--verify" => run_options.verify = Some(PathBuf::from(value()?)),
+            "--visualize" => {
+                let name = value()?;
+                day = Some(parse_day(registry, name)?);
+            }
+            "--trace" => {
+                let name = value()?;
+                trace_day = Some(parse_day(registry, name)?);
+            }
+            "--trace-level" => {
+                let name = value()?;
+                trace_level =
+                    Level::parse(name).ok_or_else(|| format!("Unknown trace level: {}", name))?;
+            }
+            "--output" => output_dir = Some(PathBuf::from(value()?)),
+            "--format" => {
+                let name = value()?;
+                formats
+                    .push(Format::parse(name).ok_or_else(|| format!("Unknown format: {}", name))?);
+            }
+            "--scale" => {
+                let name = value()?;
+                scale = name
+                    .parse()
+                    .ok()
+                    .filter(|&scale| scale > 0)
+                    .ok_or_else(|| format!("Invalid scale: {}", name))?;
+            }
+            _ => return Err(format!("Unknown argument: {}", arg)),
+        }
+    }
+
+    let is_run = run_options.json || run_options.verify.is_some();
+    if is_run && (day.is_some() || trace_day.is_some()) {
+        return Err("--json and --verify can't be combined with the other options".into());
+    }
+    if is_run {
+        return Ok(Command::Run(run_options));
+    }
+
+    if let Some(traced_day) = trace_day {
+        if day.is_some() || output_dir.is_some() || !formats.is_empty() {
+            return Err("--trace can't be combined with the visualisation options".into());
+        }
+        return Ok(Command::Trace(TraceOptions {
+            day: traced_day,
+            level: trace_level,
+        }));
+    }
+    let day = day.ok_or("--visualize is required with the visualisation options")?;
+    if formats.is_empty() {
+        formats.extend(Format::ALL);
+    }
+    Ok(Command::Visualize(VisualizeOptions {
+        day,
+        output_dir: output_dir.unwrap_or_else(|| {
+            PathBuf::from(format!("visualizations/{}/day_{}", day.year, day.name))
+        }),
+        formats,
+        scale,
+    }))
+}
+
+fn visualize_day(options: &VisualizeOptions) -> Result<(), String> {
+    let day = options.day;
+    let visualize = day
+        .visualize
+        .ok_or_else(|| format!("Day {} has no visualisation", day.label()))?;
+    let frames = visualize(day.input).map_err(|error| error.to_string())?;
+    visualize::write_frames(
+        &frames,
+        &options.output_dir,
+        &options.formats,
+        options.scale,
+    )
+    .map_err(|error| format!("Failed to write frames: {}", error))?;
+    println!(
+        "Day {}: {} frames written to {}",
+        day.label(),
+        frames.len(),
+        options.output_dir.display()
+    );
+    Ok(())
+}
+
+// The day's own view of how it got to its answers, one row per input line.
+fn explain_day(options: &ExplainOptions) -> Result<(), String> {
+    let day = options.day;
+    let explain = day
+        .explain
+        .ok_or_else(|| format!("Day {} has no explanation", day.label()))?;
+    let input = match &options.input {
+        Some(path) => std::fs::read_to_string(path)
+            .map_err(|error| format!("Failed to read {}: {}", path.display(), error))?,
+        None => day.input.to_string(),
+    };
+    println!("{}", explain(&input).map_err(|error| error.to_string())?);
+    Ok(())
+}
+
+fn trace_day(options: &TraceOptions) -> Result<(), String> {
+    if !trace::ENABLED {
+        return Err("Tracing is compiled out of release builds without the trace feature".into());
+    }
+    let day = options.day;
+    trace::init(vec![(format!("day_{}", day.name), options.level)]);
+    execute_day(day);
+    Ok(())
+}
+
+fn run_days(registry: &Registry, options: &RunOptions) -> Result<(), String> {
+    let expected = match &options.verify {
+        Some(path) => {
+            let data = std::fs::read_to_string(path)
+                .map_err(|error| format!("Failed to read {}: {}", path.display(), error))?;
+            Some(report::parse_expected(&data)?)
+        }
+        None => None,
+    };
+
+    let mut results = Vec::with_capacity(2 * registry.days().len());
+    for day in registry.days() {
+        for part in [1, 2] {
+            let result = solve_part(day, part);
+            if !options.json {
+                print_result(&result, expected.as_ref());
+            }
+            results.push(result);
+        }
+    }
+    if options.json {
+        println!("{}", report::json(&results, expected.as_ref()));
+    }
+
+    let nb_wrong = expected.as_ref().map_or(0, |expected| {
+        results
+            .iter()
+            .filter(|result| result.is_correct(expected) == Some(false))
+            .count()
+    });
+    if nb_wrong > 0 {
+        return Err(format!("{} wrong answers", nb_wrong));
+    }
+    Ok(())
+}
+
+fn generate_day(options: &GenerateOptions) {
+    let data = (options.day.generate)(&mut Rng::new(options.seed), options.size);
+    println!("{}", data);
+}
+
+fn bench_days(options: &BenchOptions) {
+    println!("{}", bench::CSV_HEADER);
+    for day in &options.days {
+        let measurements = bench::measure(
+            day.generate,
+            day.part_1,
+            day.part_2,
+            &options.sizes,
+            options.seed,
+            options.nb_runs,
+        );
+        for line in bench::csv_lines(&day.label(), &measurements) {
+            println!("{}", line);
+        }
+    }
+}
+
+// The days of every year linked into the binary.
+pub fn main(years: &[&'static [Day]]) -> ExitCode {
+    let registry = Registry::new(years);
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    let result = match parse_args(&registry, &args) {
+        Ok(Command::Run(options)) => run_days(&registry, &options),
+        Ok(Command::Explain(options)) => explain_day(&options),
+        Ok(Command::Visualize(options)) => visualize_day(&options),
+        Ok(Command::Trace(options)) => trace_day(&options),
+        Ok(Command::Generate(options)) => {
+            generate_day(&options);
+            Ok(())
+        }
+        Ok(Command::Bench(options)) => {
+            bench_days(&options);
+            Ok(())
+        }
+        Err(error) => Err(format!("{}\n{}", error, USAGE)),
+    };
+
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(error) => {
+            eprintln!("{}", error);
+            ExitCode::FAILURE
+        }
+    }
+}
@@ -0,0 +1,331 @@
+/*
+    A small k-d tree over integer points, for nearest-neighbour problems.
+
+    The tree is implicit: the point indices are stored in a single vector,
+    each node being the median of its slice, split on one axis per depth level.
+
+    Distances are squared euclidean distances, in u128 so they are exact for any
+    coordinates within ±2^62 in up to 3 dimensions. Ties are broken by point index
+    so that every query is deterministic. It matters for the closest pairs iterator,
+    which asks for more and more neighbours of the same point and expects the
+    longer answers to start with the shorter ones.
+*/
+
+use std::{cmp::Reverse, collections::BinaryHeap};
+
+pub fn square_distance<const D: usize>(a: &[i64; D], b: &[i64; D]) -> u128 {
+    a.iter()
+        .zip(b.iter())
+        .map(|(u, v)| square(u.abs_diff(*v)))
+        .sum()
+}
+
+fn square(d: u64) -> u128 {
+    d as u128 * d as u128
+}
+
+pub struct KdTree<const D: usize> {
+    points: Vec<[i64; D]>,
+    // point indices, arranged as an implicit balanced tree
+    nodes: Vec<usize>,
+}
+
+impl<const D: usize> KdTree<D> {
+    pub fn new(points: Vec<[i64; D]>) -> Self {
+        let mut nodes: Vec<usize> = (0..points.len()).collect();
+        build(&points, &mut nodes, 0);
+        KdTree { points, nodes }
+    }
+
+    pub fn len(&self) -> usize {
+        self.points.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.points.is_empty()
+    }
+
+    pub fn point(&self, index: usize) -> &[i64; D] {
+        &self.points[index]
+    }
+
+    // The k closest points to query, sorted by (distance, index).
+    pub fn k_nearest(&self, query: &[i64; D], k: usize) -> Vec<(usize, u128)> {
+        if k == 0 {
+            return Vec::new();
+        }
+        let mut heap: BinaryHeap<(u128, usize)> = BinaryHeap::with_capacity(k + 1);
+        self.k_nearest_rec(query, k, 0, self.nodes.len(), 0, &mut heap);
+        heap.into_sorted_vec()
+            .into_iter()
+            .map(|(distance, index)| (index, distance))
+            .collect()
+    }
+
+    pub fn nearest(&self, query: &[i64; D]) -> Option<(usize, u128)> {
+        self.k_nearest(query, 1).pop()
+    }
+
+    fn k_nearest_rec(
+        &self,
+        query: &[i64; D],
+        k: usize,
+        start: usize,
+        end: usize,
+        depth: usize,
+        heap: &mut BinaryHeap<(u128, usize)>,
+    ) {
+        if start >= end {
+            return;
+        }
+        let mid = start + (end - start) / 2;
+        let index = self.nodes[mid];
+        let point = &self.points[index];
+
+        let candidate = (square_distance(query, point), index);
+        if heap.len() < k {
+            heap.push(candidate);
+        } else if let Some(worst) = heap.peek()
+            && candidate < *worst
+        {
+            heap.pop();
+            heap.push(candidate);
+        }
+
+        let axis = depth % D;
+        let before = query[axis] < point[axis];
+        let (near, far) = if before {
+            ((start, mid), (mid + 1, end))
+        } else {
+            ((mid + 1, end), (start, mid))
+        };
+        self.k_nearest_rec(query, k, near.0, near.1, depth + 1, heap);
+
+        // equal distances are kept, as a smaller index may be on the other side
+        let plane_distance = square(query[axis].abs_diff(point[axis]));
+        if heap.len() < k
+            || heap
+                .peek()
+                .is_some_and(|&(worst, _)| plane_distance <= worst)
+        {
+            self.k_nearest_rec(query, k, far.0, far.1, depth + 1, heap);
+        }
+    }
+
+    // All the points within the given squared distance, sorted by (distance, index).
+    pub fn within_radius(&self, query: &[i64; D], square_radius: u128) -> Vec<(usize, u128)> {
+        let mut found = Vec::new();
+        self.within_radius_rec(query, square_radius, 0, self.nodes.len(), 0, &mut found);
+        found.sort_unstable_by_key(|&(index, distance)| (distance, index));
+        found
+    }
+
+    fn within_radius_rec(
+        &self,
+        query: &[i64; D],
+        square_radius: u128,
+        start: usize,
+        end: usize,
+        depth: usize,
+        found: &mut Vec<(usize, u128)>,
+    ) {
+        if start >= end {
+            return;
+        }
+        let mid = start + (end - start) / 2;
+        let index = self.nodes[mid];
+        let point = &self.points[index];
+
+        let distance = square_distance(query, point);
+        if distance <= square_radius {
+            found.push((index, distance));
+        }
+
+        let axis = depth % D;
+        let before = query[axis] < point[axis];
+        let plane_distance = square(query[axis].abs_diff(point[axis]));
+        if before || plane_distance <= square_radius {
+            self.within_radius_rec(query, square_radius, start, mid, depth + 1, found);
+        }
+        if !before || plane_distance <= square_radius {
+            self.within_radius_rec(query, square_radius, mid + 1, end, depth + 1, found);
+        }
+    }
+
+    // Every pair of distinct points, by increasing distance, without computing all of them.
+    pub fn closest_pairs(&self) -> ClosestPairs<'_, D> {
+        let mut pairs = ClosestPairs {
+            tree: self,
+            neighbours: vec![Vec::new(); self.points.len()],
+            heap: BinaryHeap::with_capacity(self.points.len()),
+        };
+        for index in 0..self.points.len() {
+            pairs.push_next(index, 0);
+        }
+        pairs
+    }
+}
+
+fn build<const D: usize>(points: &[[i64; D]], nodes: &mut [usize], depth: usize) {
+    if nodes.len() <= 1 {
+        return;
+    }
+    let axis = depth % D;
+    let mid = nodes.len() / 2;
+    nodes.select_nth_unstable_by_key(mid, |&index| points[index][axis]);
+    let (left, right) = nodes.split_at_mut(mid);
+    build(points, left, depth + 1);
+    build(points, &mut right[1..], depth + 1);
+}
+
+/*
+    Every point keeps a cursor on its list of nearest neighbours, and the heap contains
+    the next neighbour of every point. The smallest one in the heap is the next closest pair.
+    Each pair is seen twice, once from each side, and only given out from the lowest index.
+
+    The neighbour lists are fetched lazily and doubled in size when exhausted.
+*/
+pub struct ClosestPairs<'a, const D: usize> {
+    tree: &'a KdTree<D>,
+    neighbours: Vec<Vec<(usize, u128)>>,
+    // (distance, point index, neighbour index, rank in the neighbour list)
+    heap: BinaryHeap<Reverse<(u128, usize, usize, usize)>>,
+}
+
+const INITIAL_NB_NEIGHBOURS: usize = 8;
+
+impl<const D: usize> ClosestPairs<'_, D> {
+    fn push_next(&mut self, index: usize, rank: usize) {
+        let neighbours = &mut self.neighbours[index];
+        if rank >= neighbours.len() {
+            let nb_points = self.tree.len();
+            if neighbours.len() >= nb_points - 1 {
+                return;
+            }
+            // + 1 because the point itself is its closest neighbour
+            let k = (neighbours.len() * 2).max(INITIAL_NB_NEIGHBOURS) + 1;
+            *neighbours = self
+                .tree
+                .k_nearest(self.tree.point(index), k.min(nb_points))
+                .into_iter()
+                .filter(|&(other, _)| other != index)
+                .collect();
+            if rank >= neighbours.len() {
+                return;
+            }
+        }
+        let (other, distance) = neighbours[rank];
+        self.heap.push(Reverse((distance, index, other, rank)));
+    }
+}
+
+impl<const D: usize> Iterator for ClosestPairs<'_, D> {
+    // (index a, index b, squared distance), with index a < index b
+    type Item = (usize, usize, u128);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while let Some(Reverse((distance, index, other, rank))) = self.heap.pop() {
+            self.push_next(index, rank + 1);
+            if index < other {
+                return Some((index, other, distance));
+            }
+        }
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn pseudo_random_points(n: usize) -> Vec<[i64; 3]> {
+        let mut state = 0x2545F4914F6CDD1D_u64;
+        let mut next = || {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            (state % 1000) as i64
+        };
+        (0..n).map(|_| [next(), next(), next()]).collect()
+    }
+
+    #[test]
+    fn test_k_nearest() {
+        let points = pseudo_random_points(200);
+        let tree = KdTree::new(points.clone());
+        for query in points.iter().take(20) {
+            let mut expected: Vec<(usize, u128)> = points
+                .iter()
+                .enumerate()
+                .map(|(index, point)| (index, square_distance(query, point)))
+                .collect();
+            expected.sort_unstable_by_key(|&(index, distance)| (distance, index));
+            expected.truncate(7);
+            assert_eq!(tree.k_nearest(query, 7), expected);
+        }
+    }
+
+    #[test]
+    fn test_within_radius() {
+        let points = pseudo_random_points(200);
+        let tree = KdTree::new(points.clone());
+        let query = [500, 500, 500];
+        let found = tree.within_radius(&query, 200 * 200);
+        let expected = points
+            .iter()
+            .filter(|point| square_distance(&query, point) <= 200 * 200)
+            .count();
+        assert_eq!(found.len(), expected);
+        assert!(found.windows(2).all(|w| w[0].1 <= w[1].1));
+    }
+
+    #[test]
+    fn test_closest_pairs() {
+        let points = pseudo_random_points(60);
+        let tree = KdTree::new(points.clone());
+        let pairs: Vec<(usize, usize, u128)> = tree.closest_pairs().collect();
+
+        let mut expected = Vec::new();
+        for a in 0..points.len() {
+            for b in a + 1..points.len() {
+                expected.push((a, b, square_distance(&points[a], &points[b])));
+            }
+        }
+        expected.sort_unstable_by_key(|&(a, b, distance)| (distance, a, b));
+
+        assert_eq!(pairs.len(), expected.len());
+        let distances: Vec<u128> = pairs.iter().map(|p| p.2).collect();
+        let expected_distances: Vec<u128> = expected.iter().map(|p| p.2).collect();
+        assert_eq!(distances, expected_distances);
+    }
+
+    #[test]
+    fn test_large_coordinates() {
+        const FAR: i64 = 1 << 62;
+        let points = vec![[-FAR, -FAR, -FAR], [FAR, FAR, FAR], [FAR, FAR, FAR - 1]];
+        let tree = KdTree::new(points);
+        assert_eq!(
+            tree.nearest(&[FAR, FAR, 0]),
+            Some((2, square(FAR as u64 - 1)))
+        );
+        let far_away = tree.k_nearest(&[-FAR, -FAR, -FAR], 3);
+        assert_eq!(far_away[2], (1, 3 * square(2 * FAR as u64)));
+        assert_eq!(
+            tree.within_radius(&[0, 0, 0], 3 * square(FAR as u64)).len(),
+            3
+        );
+        assert_eq!(tree.closest_pairs().next(), Some((1, 2, 1)));
+    }
+
+    #[test]
+    fn test_small_trees() {
+        assert_eq!(KdTree::<2>::new(vec![]).closest_pairs().count(), 0);
+        assert_eq!(KdTree::new(vec![[1, 1]]).closest_pairs().count(), 0);
+        assert_eq!(
+            KdTree::new(vec![[1, 1], [4, 5]])
+                .closest_pairs()
+                .collect::<Vec<_>>(),
+            vec![(0, 1, 25)]
+        );
+    }
+}
@@ -0,0 +1,111 @@
+/*
+    Tracing of intermediate states inside the solvers, instead of commented-out println! calls.
+
+    trace!(Debug, "…") prints to stderr when the current day is traced with --trace.
+    The target is the module of the call, so day_06 for example.
+
+    The calls are compiled out of release builds, unless the trace feature is enabled.
+    They still type-check, so the variables they use don't become unused.
+*/
+
+use std::{
+    fmt::{self, Display},
+    sync::OnceLock,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Level {
+    Info,
+    Debug,
+    Trace,
+}
+
+impl Level {
+    pub fn parse(name: &str) -> Option<Level> {
+        match name {
+            "info" => Some(Level::Info),
+            "debug" => Some(Level::Debug),
+            "trace" => Some(Level::Trace),
+            _ => None,
+        }
+    }
+}
+
+impl Display for Level {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Level::Info => write!(f, "INFO"),
+            Level::Debug => write!(f, "DEBUG"),
+            Level::Trace => write!(f, "TRACE"),
+        }
+    }
+}
+
+// (target, most verbose level shown)
+static FILTER: OnceLock<Vec<(String, Level)>> = OnceLock::new();
+
+// Can only be done once, before running the days.
+pub fn init(filter: Vec<(String, Level)>) {
+    FILTER.set(filter).expect("Tracing is already initialised");
+}
+
+pub const ENABLED: bool = cfg!(any(debug_assertions, feature = "trace"));
+
+// module_path!() looks like aoc_2025::day_06
+fn target(module_path: &str) -> &str {
+    module_path.rsplit("::").next().unwrap_or(module_path)
+}
+
+pub fn enabled(module_path: &str, level: Level) -> bool {
+    let Some(filter) = FILTER.get() else {
+        return false;
+    };
+    let target = target(module_path);
+    filter
+        .iter()
+        .any(|(filter_target, max_level)| filter_target == target && level <= *max_level)
+}
+
+pub fn log(module_path: &str, level: Level, message: fmt::Arguments) {
+    eprintln!("[{} {}] {}", target(module_path), level, message);
+}
+
+// Exported as __trace and re-exported below,
+// so the days can import it as trace::trace like before.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __trace {
+    ($level:ident, $($arg:tt)*) => {
+        if $crate::trace::ENABLED
+            && $crate::trace::enabled(module_path!(), $crate::trace::Level::$level)
+        {
+            $crate::trace::log(module_path!(), $crate::trace::Level::$level, format_args!($($arg)*));
+        }
+    };
+}
+
+pub use crate::__trace as trace;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_target() {
+        assert_eq!(target("aoc_2025::day_06"), "day_06");
+        assert_eq!(target("day_06"), "day_06");
+    }
+
+    #[test]
+    fn test_levels() {
+        assert!(Level::Info < Level::Debug);
+        assert_eq!(Level::parse("trace"), Some(Level::Trace));
+        assert_eq!(Level::parse("loud"), None);
+    }
+
+    #[test]
+    fn test_disabled_by_default() {
+        // the tests never initialise the filter
+        assert!(!enabled("aoc_2025::day_06", Level::Info));
+    }
+}
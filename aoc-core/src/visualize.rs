@@ -0,0 +1,303 @@
+/*
+    Visualisation of the day models, instead of commented-out println! calls.
+
+    A model renders itself as frames, grids of coloured symbols. The frames are then
+    written as ASCII text, PPM, PNG or SVG images by this module, so the days only
+    decide what goes in each cell.
+
+    The PNG encoder is the simplest possible one: no compression at all,
+    only stored deflate blocks. The files are big but it doesn't need any dependency.
+*/
+
+use std::{
+    fs,
+    io::{self, Write},
+    path::Path,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Rgb(pub u8, pub u8, pub u8);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Pixel {
+    pub symbol: char,
+    pub color: Rgb,
+}
+
+impl Pixel {
+    pub const fn new(symbol: char, color: Rgb) -> Self {
+        Pixel { symbol, color }
+    }
+}
+
+pub trait Visualize {
+    fn frames(&self) -> Vec<Frame>;
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Frame {
+    width: usize,
+    height: usize,
+    background: Pixel,
+    pixels: Vec<Pixel>,
+}
+
+impl Frame {
+    pub fn new(width: usize, height: usize, background: Pixel) -> Self {
+        Frame {
+            width,
+            height,
+            background,
+            pixels: vec![background; width * height],
+        }
+    }
+
+    pub fn get(&self, row: usize, col: usize) -> Pixel {
+        self.pixels[row * self.width + col]
+    }
+
+    pub fn set(&mut self, row: usize, col: usize, pixel: Pixel) {
+        self.pixels[row * self.width + col] = pixel;
+    }
+
+    pub fn to_ascii(&self) -> String {
+        let mut ascii = String::with_capacity((self.width + 1) * self.height);
+        for row in self.pixels.chunks(self.width.max(1)) {
+            ascii.extend(row.iter().map(|pixel| pixel.symbol));
+            ascii.push('\n');
+        }
+        ascii
+    }
+
+    // Raw RGB rows, each cell being scale*scale pixels.
+    fn scanlines(&self, scale: usize) -> impl Iterator<Item = Vec<u8>> + '_ {
+        (0..self.height * scale).map(move |y| {
+            let row = y / scale;
+            let mut scanline = Vec::with_capacity(self.width * scale * 3);
+            for col in 0..self.width {
+                let Rgb(r, g, b) = self.get(row, col).color;
+                for _ in 0..scale {
+                    scanline.extend_from_slice(&[r, g, b]);
+                }
+            }
+            scanline
+        })
+    }
+
+    pub fn to_ppm(&self, scale: usize) -> Vec<u8> {
+        let mut ppm =
+            format!("P6\n{} {}\n255\n", self.width * scale, self.height * scale).into_bytes();
+        for scanline in self.scanlines(scale) {
+            ppm.extend(scanline);
+        }
+        ppm
+    }
+
+    pub fn to_png(&self, scale: usize) -> Vec<u8> {
+        let mut raw = Vec::new();
+        for scanline in self.scanlines(scale) {
+            raw.push(0); // no filter
+            raw.extend(scanline);
+        }
+
+        let mut ihdr = Vec::with_capacity(13);
+        ihdr.extend_from_slice(&((self.width * scale) as u32).to_be_bytes());
+        ihdr.extend_from_slice(&((self.height * scale) as u32).to_be_bytes());
+        // 8 bits per channel, RGB, deflate, no filter, no interlace
+        ihdr.extend_from_slice(&[8, 2, 0, 0, 0]);
+
+        let mut png = b"\x89PNG\r\n\x1a\n".to_vec();
+        write_png_chunk(&mut png, b"IHDR", &ihdr);
+        write_png_chunk(&mut png, b"IDAT", &zlib_stored(&raw));
+        write_png_chunk(&mut png, b"IEND", &[]);
+        png
+    }
+
+    pub fn to_svg(&self, scale: usize) -> String {
+        let color = |Rgb(r, g, b): Rgb| format!("#{:02x}{:02x}{:02x}", r, g, b);
+        let mut svg = format!(
+            "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{}\" height=\"{}\">\n",
+            self.width * scale,
+            self.height * scale
+        );
+        svg.push_str(&format!(
+            "<rect width=\"100%\" height=\"100%\" fill=\"{}\"/>\n",
+            color(self.background.color)
+        ));
+        for row in 0..self.height {
+            for col in 0..self.width {
+                let pixel = self.get(row, col);
+                if pixel.color != self.background.color {
+                    svg.push_str(&format!(
+                        "<rect x=\"{}\" y=\"{}\" width=\"{}\" height=\"{}\" fill=\"{}\"/>\n",
+                        col * scale,
+                        row * scale,
+                        scale,
+                        scale,
+                        color(pixel.color)
+                    ));
+                }
+            }
+        }
+        svg.push_str("</svg>\n");
+        svg
+    }
+}
+
+fn crc32(bytes: &[u8]) -> u32 {
+    let mut crc = 0xFFFF_FFFF_u32;
+    for &byte in bytes {
+        crc ^= byte as u32;
+        for _ in 0..8 {
+            crc = if crc & 1 == 1 {
+                (crc >> 1) ^ 0xEDB8_8320
+            } else {
+                crc >> 1
+            };
+        }
+    }
+    !crc
+}
+
+fn adler32(bytes: &[u8]) -> u32 {
+    let (mut a, mut b) = (1_u32, 0_u32);
+    for &byte in bytes {
+        a = (a + byte as u32) % 65521;
+        b = (b + a) % 65521;
+    }
+    (b << 16) | a
+}
+
+fn write_png_chunk(png: &mut Vec<u8>, chunk_type: &[u8; 4], data: &[u8]) {
+    png.extend_from_slice(&(data.len() as u32).to_be_bytes());
+    let start = png.len();
+    png.extend_from_slice(chunk_type);
+    png.extend_from_slice(data);
+    let crc = crc32(&png[start..]);
+    png.extend_from_slice(&crc.to_be_bytes());
+}
+
+// zlib stream made of uncompressed deflate blocks
+fn zlib_stored(data: &[u8]) -> Vec<u8> {
+    let mut zlib = vec![0x78, 0x01];
+    let mut blocks = data.chunks(65535).peekable();
+    if blocks.peek().is_none() {
+        zlib.extend_from_slice(&[1, 0, 0, 0xFF, 0xFF]);
+    }
+    while let Some(block) = blocks.next() {
+        let is_final = blocks.peek().is_none();
+        zlib.push(is_final as u8);
+        let len = block.len() as u16;
+        zlib.extend_from_slice(&len.to_le_bytes());
+        zlib.extend_from_slice(&(!len).to_le_bytes());
+        zlib.extend_from_slice(block);
+    }
+    zlib.extend_from_slice(&adler32(data).to_be_bytes());
+    zlib
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    Ascii,
+    Ppm,
+    Png,
+    Svg,
+}
+
+impl Format {
+    pub const ALL: [Format; 4] = [Format::Ascii, Format::Ppm, Format::Png, Format::Svg];
+
+    pub fn parse(name: &str) -> Option<Format> {
+        match name {
+            "ascii" | "txt" => Some(Format::Ascii),
+            "ppm" => Some(Format::Ppm),
+            "png" => Some(Format::Png),
+            "svg" => Some(Format::Svg),
+            _ => None,
+        }
+    }
+
+    fn extension(&self) -> &'static str {
+        match self {
+            Format::Ascii => "txt",
+            Format::Ppm => "ppm",
+            Format::Png => "png",
+            Format::Svg => "svg",
+        }
+    }
+}
+
+// Write frame_0000.png, frame_0001.png… in the output directory.
+pub fn write_frames(
+    frames: &[Frame],
+    output_dir: &Path,
+    formats: &[Format],
+    scale: usize,
+) -> io::Result<()> {
+    fs::create_dir_all(output_dir)?;
+    for (index, frame) in frames.iter().enumerate() {
+        for format in formats {
+            let path = output_dir.join(format!("frame_{:04}.{}", index, format.extension()));
+            let mut file = fs::File::create(path)?;
+            match format {
+                Format::Ascii => file.write_all(frame.to_ascii().as_bytes())?,
+                Format::Ppm => file.write_all(&frame.to_ppm(scale))?,
+                Format::Png => file.write_all(&frame.to_png(scale))?,
+                Format::Svg => file.write_all(frame.to_svg(scale).as_bytes())?,
+            }
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const BLACK: Pixel = Pixel::new('.', Rgb(0, 0, 0));
+    const WHITE: Pixel = Pixel::new('#', Rgb(255, 255, 255));
+
+    fn checkerboard() -> Frame {
+        let mut frame = Frame::new(3, 2, BLACK);
+        frame.set(0, 0, WHITE);
+        frame.set(1, 1, WHITE);
+        frame.set(0, 2, WHITE);
+        frame
+    }
+
+    #[test]
+    fn test_ascii() {
+        assert_eq!(checkerboard().to_ascii(), "#.#\n.#.\n");
+    }
+
+    #[test]
+    fn test_ppm() {
+        let ppm = checkerboard().to_ppm(2);
+        let header = b"P6\n6 4\n255\n";
+        assert_eq!(&ppm[..header.len()], header);
+        assert_eq!(ppm.len(), header.len() + 6 * 4 * 3);
+    }
+
+    #[test]
+    fn test_checksums() {
+        assert_eq!(crc32(b"IEND"), 0xAE42_6082);
+        assert_eq!(adler32(b"Wikipedia"), 0x11E6_0398);
+    }
+
+    #[test]
+    fn test_png() {
+        let png = checkerboard().to_png(1);
+        assert_eq!(&png[..8], b"\x89PNG\r\n\x1a\n");
+        assert_eq!(&png[12..16], b"IHDR");
+        assert_eq!(&png[16..20], &3_u32.to_be_bytes());
+        assert_eq!(&png[20..24], &2_u32.to_be_bytes());
+        assert_eq!(&png[png.len() - 12..], b"\0\0\0\0IEND\xAE\x42\x60\x82");
+    }
+
+    #[test]
+    fn test_svg() {
+        let svg = checkerboard().to_svg(10);
+        assert!(svg.starts_with("<svg"));
+        assert_eq!(svg.matches("fill=\"#ffffff\"").count(), 3);
+    }
+}
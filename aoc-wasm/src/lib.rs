@@ -0,0 +1,17 @@
+/*
+    The solvers for a web page, as a WebAssembly module:
+
+    cargo build -p aoc-wasm --release --target wasm32-unknown-unknown
+    wasm-bindgen --target web --out-dir pkg target/wasm32-unknown-unknown/release/aoc_wasm.wasm
+
+    The days run on a single thread, and the page gives the inputs.
+*/
+
+use aoc_core::registry::Registry;
+use wasm_bindgen::prelude::*;
+
+// solve(9, 2, input) in JavaScript, it throws the error message when there is no answer.
+#[wasm_bindgen]
+pub fn solve(day: u32, part: u8, input: &str) -> Result<String, String> {
+    Registry::new(&[aoc_2025::DAYS]).solve(&day.to_string(), part, input)
+}
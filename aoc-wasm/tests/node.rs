@@ -0,0 +1,33 @@
+/*
+    Run in node, headless:
+    cargo test -p aoc-wasm --target wasm32-unknown-unknown
+    with wasm-bindgen-test-runner from wasm-bindgen-cli, see .cargo/config.toml.
+*/
+
+#![cfg(target_arch = "wasm32")]
+
+use aoc_wasm::solve;
+use wasm_bindgen_test::wasm_bindgen_test;
+
+#[wasm_bindgen_test]
+fn test_solve() {
+    let example = "L68\nL30\nR48\nL5\nR60\nL55\nL1\nL99\nR14\nL82";
+    assert_eq!(solve(1, 1, example), Ok("3".to_string()));
+    assert_eq!(solve(1, 2, example), Ok("6".to_string()));
+}
+
+#[wasm_bindgen_test]
+fn test_solve_without_threads() {
+    // day 12 uses par_iter, a plain iterator without the parallel feature
+    assert_eq!(
+        solve(12, 1, "4x4: 1\n6x3: 1 1\n3x3: 2"),
+        Ok("2".to_string())
+    );
+}
+
+#[wasm_bindgen_test]
+fn test_errors() {
+    assert!(solve(1, 1, "X68").is_err());
+    assert!(solve(1, 3, "L68").is_err());
+    assert!(solve(25, 1, "").is_err());
+}
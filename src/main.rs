@@ -1,62 +0,0 @@
-use paste::paste;
-use std::fmt::Display;
-
-mod day_01;
-mod day_02;
-mod day_03;
-mod day_04;
-mod day_05;
-mod day_06;
-mod day_07;
-mod day_08;
-mod day_09;
-mod day_10;
-mod day_11;
-mod day_12;
-
-fn execute_day<F, G, D1, D2>(day: &str, data: &str, part_1: F, part_2: G)
-where
-    F: Fn(&str) -> D1,
-    G: Fn(&str) -> D2,
-    D1: Display,
-    D2: Display,
-{
-    let now = std::time::Instant::now();
-    let result_part_1 = part_1(data);
-    let elapsed = now.elapsed();
-    println!("Day {}, part 1 ({:?}):\t{}", day, elapsed, result_part_1);
-    let now = std::time::Instant::now();
-    let result_part_2 = part_2(data);
-    let elapsed = now.elapsed();
-    println!("Day {}, part 2 ({:?}):\t{}", day, elapsed, result_part_2);
-}
-
-macro_rules! execute_day {
-    ($day:expr, $part_1:expr, $part_2:expr) => {
-        execute_day(
-            $day,
-            include_str!(concat!("../inputs/day_", $day, ".txt")),
-            $part_1,
-            $part_2,
-        );
-    };
-}
-
-macro_rules! execute_days {
-    ($($day:literal),*) => {
-        $(
-            paste! {
-                execute_day!(
-                    $day,
-                    [<day_ $day>]::[<day_ $day _part_1>],
-                    [<day_ $day>]::[<day_ $day _part_2>]
-                );
-            }
-        )*
-    };
-}
-
-fn main() {
-    execute_days!("01", "02", "03", "04", "05", "06", "07");
-    execute_days!("08", "09", "10", "11", "12");
-}